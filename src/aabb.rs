@@ -0,0 +1,148 @@
+//! Axis-aligned bounding boxes in 2 and 3 dimentional space.
+//! All bounds are inclusive: points on the border are contained, and boxes that only touch along an edge or face intersect.
+use crate::{vector2::Vector2, vector3::Vector3};
+
+///2D axis-aligned bounding box
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Aabb2 {
+    pub min: Vector2,
+    pub max: Vector2
+}
+
+///3D axis-aligned bounding box
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Aabb3 {
+    pub min: Vector3,
+    pub max: Vector3
+}
+
+impl Aabb2 {
+
+    #[inline(always)]
+    pub fn new(min: Vector2, max: Vector2) -> Self {
+        Self { min, max }
+    }
+
+    /// Determines whether or not a [`Vector2`] interpreted as a point lies inside of an [`Aabb2`] or on its border.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::aabb::*;
+    /// use cute_gorl::vector2::*;
+    /// let b = Aabb2 { min: Vector2 { x: 0., y: 0. }, max: Vector2 { x: 2., y: 1. } };
+    /// assert!(b.contains(&Vector2 { x: 1., y: 0.5 }));
+    /// assert!(b.contains(&Vector2 { x: 2., y: 1. }));
+    /// assert!( !(b.contains(&Vector2 { x: 2.5, y: 0.5 })) );
+    /// ```
+    pub fn contains(&self, p: &Vector2) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x &&
+        p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    /// Determines whether or not two [`Aabb2`]s overlap. Boxes that only share an edge or a corner count as intersecting.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::aabb::*;
+    /// use cute_gorl::vector2::*;
+    /// let b1 = Aabb2 { min: Vector2 { x: 0., y: 0. }, max: Vector2 { x: 1., y: 1. } };
+    /// let b2 = Aabb2 { min: Vector2 { x: 0.5, y: 0.5 }, max: Vector2 { x: 2., y: 2. } };
+    /// let b3 = Aabb2 { min: Vector2 { x: 3., y: 0. }, max: Vector2 { x: 4., y: 1. } };
+    /// assert!(b1.intersects(&b2));
+    /// assert!( !(b1.intersects(&b3)) );
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+        self.min.y <= other.max.y && self.max.y >= other.min.y
+    }
+
+    /// Grows an [`Aabb2`] just enough to contain a [`Vector2`] interpreted as a point.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::aabb::*;
+    /// use cute_gorl::vector2::*;
+    /// let mut b = Aabb2 { min: Vector2 { x: 0., y: 0. }, max: Vector2 { x: 1., y: 1. } };
+    /// b.expand_to_include(&Vector2 { x: -1., y: 3. });
+    /// assert_eq!(b, Aabb2 { min: Vector2 { x: -1., y: 0. }, max: Vector2 { x: 1., y: 3. } });
+    /// ```
+    pub fn expand_to_include(&mut self, p: &Vector2) {
+        self.min = Vector2::min(&self.min, p);
+        self.max = Vector2::max(&self.max, p);
+    }
+
+    /// The point in the middle of an [`Aabb2`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::aabb::*;
+    /// use cute_gorl::vector2::*;
+    /// let b = Aabb2 { min: Vector2 { x: -1., y: 0. }, max: Vector2 { x: 3., y: 1. } };
+    /// assert_eq!(b.center(), Vector2 { x: 1., y: 0.5 });
+    /// ```
+    pub fn center(&self) -> Vector2 {
+        Vector2::lerp(&self.min, &self.max, 0.5)
+    }
+}
+
+impl Aabb3 {
+
+    #[inline(always)]
+    pub fn new(min: Vector3, max: Vector3) -> Self {
+        Self { min, max }
+    }
+
+    /// Determines whether or not a [`Vector3`] interpreted as a point lies inside of an [`Aabb3`] or on its border.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::aabb::*;
+    /// use cute_gorl::vector3::*;
+    /// let b = Aabb3 { min: Vector3 { x: 0., y: 0., z: 0. }, max: Vector3 { x: 2., y: 1., z: 1. } };
+    /// assert!(b.contains(&Vector3 { x: 1., y: 0.5, z: 1. }));
+    /// assert!( !(b.contains(&Vector3 { x: 1., y: 0.5, z: 1.5 })) );
+    /// ```
+    pub fn contains(&self, p: &Vector3) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x &&
+        p.y >= self.min.y && p.y <= self.max.y &&
+        p.z >= self.min.z && p.z <= self.max.z
+    }
+
+    /// Determines whether or not two [`Aabb3`]s overlap. Boxes that only share a face, edge or corner count as intersecting.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::aabb::*;
+    /// use cute_gorl::vector3::*;
+    /// let b1 = Aabb3 { min: Vector3 { x: 0., y: 0., z: 0. }, max: Vector3 { x: 1., y: 1., z: 1. } };
+    /// let b2 = Aabb3 { min: Vector3 { x: 0.5, y: 0.5, z: 0.5 }, max: Vector3 { x: 2., y: 2., z: 2. } };
+    /// let b3 = Aabb3 { min: Vector3 { x: 0., y: 0., z: 3. }, max: Vector3 { x: 1., y: 1., z: 4. } };
+    /// assert!(b1.intersects(&b2));
+    /// assert!( !(b1.intersects(&b3)) );
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+        self.min.y <= other.max.y && self.max.y >= other.min.y &&
+        self.min.z <= other.max.z && self.max.z >= other.min.z
+    }
+
+    /// Grows an [`Aabb3`] just enough to contain a [`Vector3`] interpreted as a point.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::aabb::*;
+    /// use cute_gorl::vector3::*;
+    /// let mut b = Aabb3 { min: Vector3 { x: 0., y: 0., z: 0. }, max: Vector3 { x: 1., y: 1., z: 1. } };
+    /// b.expand_to_include(&Vector3 { x: -1., y: 0.5, z: 3. });
+    /// assert_eq!(b, Aabb3 { min: Vector3 { x: -1., y: 0., z: 0. }, max: Vector3 { x: 1., y: 1., z: 3. } });
+    /// ```
+    pub fn expand_to_include(&mut self, p: &Vector3) {
+        self.min = Vector3::min(&self.min, p);
+        self.max = Vector3::max(&self.max, p);
+    }
+
+    /// The point in the middle of an [`Aabb3`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::aabb::*;
+    /// use cute_gorl::vector3::*;
+    /// let b = Aabb3 { min: Vector3 { x: -1., y: 0., z: 2. }, max: Vector3 { x: 3., y: 1., z: 4. } };
+    /// assert_eq!(b.center(), Vector3 { x: 1., y: 0.5, z: 3. });
+    /// ```
+    pub fn center(&self) -> Vector3 {
+        Vector3::lerp(&self.min, &self.max, 0.5)
+    }
+}
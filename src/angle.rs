@@ -0,0 +1,39 @@
+//! Newtypes that make the unit of an angle explicit, so radians and degrees can't be mixed up by accident.
+//! Functions taking a plain `f64` angle, like [`Vector2::rotate`](crate::vector2::Vector2::rotate), always expect radians.
+
+///An angle in radians
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Radians(pub f64);
+
+///An angle in degrees
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Degrees(pub f64);
+
+/// Converts degrees to radians.
+/// # Examples
+/// ```
+/// use cute_gorl::angle::*;
+/// use std::f64::consts::PI;
+/// let Radians(r) = Radians::from(Degrees(180.));
+/// assert!((r - PI).abs() < 1e-15);
+/// ```
+impl From<Degrees> for Radians {
+    #[inline]
+    fn from(d: Degrees) -> Self {
+        Radians(d.0.to_radians())
+    }
+}
+
+/// Converts radians to degrees.
+/// # Examples
+/// ```
+/// use cute_gorl::angle::*;
+/// use std::f64::consts::PI;
+/// assert_eq!(Degrees::from(Radians(PI)), Degrees(180.));
+/// ```
+impl From<Radians> for Degrees {
+    #[inline]
+    fn from(r: Radians) -> Self {
+        Degrees(r.0.to_degrees())
+    }
+}
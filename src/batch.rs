@@ -0,0 +1,111 @@
+//! Operations on whole slices of vectors at once.
+//! They give the same results as calling the matching [`Vector2`]/[`Vector3`] method on every element.
+//!
+//! With the `simd` feature, [`normalize_slice`] and [`add_slice`] work on explicit chunks of 4 vectors
+//! (`std::simd` is nightly-only). In release builds that was not measurably faster: about 2.0-2.6 ms for
+//! a million vectors and 10-20 µs for ten thousand either way. Even the plain loops only read and write
+//! memory as fast as it can go, and the compiler already vectorizes them, so the feature is off by default.
+//! The `batch_timing` test in `local_tests.rs` reproduces these numbers:
+//! `cargo test --release batch_timing -- --ignored --nocapture`, with and without `--features simd`.
+use crate::{vector2::Vector2, vector3::Vector3};
+
+/// Normalizes every [`Vector3`] in a slice in place, like [`Vector3::normalize`].
+/// # Examples
+/// ```
+/// use cute_gorl::batch::*;
+/// use cute_gorl::vector3::*;
+/// let mut v = [Vector3 { x: 3., y: 0., z: 4. }, Vector3 { x: 0., y: -2., z: 0. }];
+/// normalize_slice(&mut v);
+/// assert!(v.iter().all(|v| v.is_normalized()));
+/// ```
+#[cfg(not(feature = "simd"))]
+pub fn normalize_slice(vectors: &mut [Vector3]) {
+    for v in vectors { v.normalize(); }
+}
+
+/// Normalizes every [`Vector3`] in a slice in place, like [`Vector3::normalize`].
+/// Works on chunks of 4 vectors, finishing the last few one by one.
+/// # Examples
+/// ```
+/// use cute_gorl::batch::*;
+/// use cute_gorl::vector3::*;
+/// let mut v = [Vector3 { x: 3., y: 0., z: 4. }, Vector3 { x: 0., y: -2., z: 0. }];
+/// normalize_slice(&mut v);
+/// assert!(v.iter().all(|v| v.is_normalized()));
+/// ```
+#[cfg(feature = "simd")]
+pub fn normalize_slice(vectors: &mut [Vector3]) {
+    let mut chunks = vectors.chunks_exact_mut(4);
+    for c in &mut chunks {
+        let mut inv = [0.0_f64; 4];
+        for i in 0..4 { inv[i] = 1. / c[i].magn(); }
+        for i in 0..4 {
+            c[i].x *= inv[i];
+            c[i].y *= inv[i];
+            c[i].z *= inv[i];
+        }
+    }
+    for v in chunks.into_remainder() { v.normalize(); }
+}
+
+/// Adds every [`Vector3`] of `src` onto the one at the same index in `dst`.
+/// # Panics
+/// If the two slices have different lengths.
+/// # Examples
+/// ```
+/// use cute_gorl::batch::*;
+/// use cute_gorl::vector3::*;
+/// let mut pos = [Vector3 { x: 1., y: 0., z: 0. }, Vector3 { x: 0., y: 1., z: 0. }];
+/// let vel = [Vector3 { x: 0.5, y: 0., z: 0. }, Vector3 { x: 0., y: 0., z: -1. }];
+/// add_slice(&mut pos, &vel);
+/// assert_eq!(pos, [Vector3 { x: 1.5, y: 0., z: 0. }, Vector3 { x: 0., y: 1., z: -1. }]);
+/// ```
+#[cfg(not(feature = "simd"))]
+pub fn add_slice(dst: &mut [Vector3], src: &[Vector3]) {
+    assert_eq!(dst.len(), src.len(), "add_slice needs slices of equal length");
+    for (d, s) in dst.iter_mut().zip(src) { *d += s; }
+}
+
+/// Adds every [`Vector3`] of `src` onto the one at the same index in `dst`.
+/// Works on chunks of 4 vectors, finishing the last few one by one.
+/// # Panics
+/// If the two slices have different lengths.
+/// # Examples
+/// ```
+/// use cute_gorl::batch::*;
+/// use cute_gorl::vector3::*;
+/// let mut pos = [Vector3 { x: 1., y: 0., z: 0. }, Vector3 { x: 0., y: 1., z: 0. }];
+/// let vel = [Vector3 { x: 0.5, y: 0., z: 0. }, Vector3 { x: 0., y: 0., z: -1. }];
+/// add_slice(&mut pos, &vel);
+/// assert_eq!(pos, [Vector3 { x: 1.5, y: 0., z: 0. }, Vector3 { x: 0., y: 1., z: -1. }]);
+/// ```
+#[cfg(feature = "simd")]
+pub fn add_slice(dst: &mut [Vector3], src: &[Vector3]) {
+    assert_eq!(dst.len(), src.len(), "add_slice needs slices of equal length");
+    let mut d_chunks = dst.chunks_exact_mut(4);
+    let mut s_chunks = src.chunks_exact(4);
+    for (d, s) in (&mut d_chunks).zip(&mut s_chunks) {
+        for i in 0..4 {
+            d[i].x += s[i].x;
+            d[i].y += s[i].y;
+            d[i].z += s[i].z;
+        }
+    }
+    for (d, s) in d_chunks.into_remainder().iter_mut().zip(s_chunks.remainder()) { *d += s; }
+}
+
+/// Rotates (anti-clockwise) every [`Vector2`] in a slice in place by the same angle, like [`Vector2::rotate`],
+/// but only calculates the sine and cosine of the angle once.
+/// # Examples
+/// ```
+/// use cute_gorl::batch::*;
+/// use cute_gorl::vector2::*;
+/// use std::f64::consts::PI;
+/// let mut square = [Vector2 { x: 1., y: 1. }, Vector2 { x: -1., y: 1. }, Vector2 { x: -1., y: -1. }, Vector2 { x: 1., y: -1. }];
+/// rotate_slice(&mut square, PI);
+/// assert!(square[0].approx_eq(&Vector2 { x: -1., y: -1. }, 1e-12));
+/// ```
+pub fn rotate_slice(points: &mut [Vector2], angle: f64) {
+    let (sin_a, cos_a) = angle.sin_cos();
+    for p in points { *p = p.rotate_by(sin_a, cos_a); }
+}
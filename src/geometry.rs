@@ -0,0 +1,258 @@
+//! Algorithms on sets of points and polygons in 2 dimentional space.
+//! Polygons are slices of [`Vector2`] vertices in order, with the last vertex connected back to the first.
+use crate::vector2::Vector2;
+
+/// Orientation of the turn `a -> b -> c`: positive if it turns anti-clockwise, negative if clockwise, `0.0` if collinear.
+#[inline]
+fn turn(a: &Vector2, b: &Vector2, c: &Vector2) -> f64 {
+    Vector2::perp_dot(&(b - a), &(c - a))
+}
+
+/// Calculates the convex hull of a number of [`Vector2`]s interpreted as points, using Andrew's monotone chain.
+/// The hull vertices are returned in counter-clockwise order, starting with the lowest-leftmost point.
+/// Points on the edges of the hull are left out, so collinear input yields just its two end points,
+/// and with fewer than 3 distinct points those points are returned as they are, sorted and deduplicated.
+/// # Examples
+/// ```
+/// use cute_gorl::geometry::*;
+/// use cute_gorl::vector2::*;
+/// let points = [
+///     Vector2 { x: 0., y: 0. },
+///     Vector2 { x: 1., y: 1. },
+///     Vector2 { x: 2., y: 0. },
+///     Vector2 { x: 1., y: 3. }
+/// ];
+/// assert_eq!(convex_hull(&points), vec![points[0], points[2], points[3]]);
+/// ```
+pub fn convex_hull(points: &[Vector2]) -> Vec<Vector2> {
+    let mut sorted: Vec<Vector2> = points.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    sorted.dedup();
+    if sorted.len() < 3 { return sorted; }
+
+    let mut hull: Vec<Vector2> = Vec::with_capacity(sorted.len() + 1);
+    // lower hull from left to right
+    for p in &sorted {
+        while hull.len() >= 2 && turn(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= 0.0 { hull.pop(); }
+        hull.push(*p);
+    }
+    // upper hull from right to left, never popping into the lower hull
+    let lower_len: usize = hull.len() + 1;
+    for p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && turn(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= 0.0 { hull.pop(); }
+        hull.push(*p);
+    }
+    // the upper hull ends where the lower one started
+    hull.pop();
+    hull
+}
+
+/// Determines whether or not a [`Vector2`] interpreted as a point lies inside of a polygon, using the even-odd rule:
+/// a ray cast from the point crosses the border of the polygon an odd number of times.
+/// Self-intersecting polygons are fine, regions that are overlapped twice count as outside.
+/// Whether points exactly on an edge count as inside is not specified, as it depends on rounding;
+/// use [`Vector2::dist_to_segment`] when the border needs special treatment.
+/// # Examples
+/// ```
+/// use cute_gorl::geometry::*;
+/// use cute_gorl::vector2::*;
+/// let square = [
+///     Vector2 { x: 0., y: 0. },
+///     Vector2 { x: 2., y: 0. },
+///     Vector2 { x: 2., y: 2. },
+///     Vector2 { x: 0., y: 2. }
+/// ];
+/// assert!(point_in_polygon(&Vector2 { x: 1., y: 1.5 }, &square));
+/// assert!( !(point_in_polygon(&Vector2 { x: 3., y: 1. }, &square)) );
+/// ```
+pub fn point_in_polygon(point: &Vector2, polygon: &[Vector2]) -> bool {
+    let mut inside: bool = false;
+    let mut prev: &Vector2 = match polygon.last() { Some(p) => p, None => return false };
+    for curr in polygon {
+        // only edges straddling the horizontal line through the point can cross the ray going right
+        if (curr.y > point.y) != (prev.y > point.y) {
+            let x_cross: f64 = curr.x + (point.y - curr.y) * (prev.x - curr.x) / (prev.y - curr.y);
+            if point.x < x_cross { inside = !inside; }
+        }
+        prev = curr;
+    }
+    inside
+}
+
+/// Calculates the signed area of a polygon using the shoelace formula.
+/// The area is positive if the vertices are in counter-clockwise order and negative if they are clockwise.
+/// # Examples
+/// ```
+/// use cute_gorl::geometry::*;
+/// use cute_gorl::vector2::*;
+/// let triangle = [Vector2 { x: 0., y: 0. }, Vector2 { x: 4., y: 0. }, Vector2 { x: 0., y: 3. }];
+/// assert_eq!(polygon_signed_area(&triangle), 6.);
+/// ```
+pub fn polygon_signed_area(polygon: &[Vector2]) -> f64 {
+    let mut prev: &Vector2 = match polygon.last() { Some(p) => p, None => return 0.0 };
+    let mut twice_area: f64 = 0.0;
+    for curr in polygon {
+        twice_area += Vector2::perp_dot(prev, curr);
+        prev = curr;
+    }
+    0.5 * twice_area
+}
+
+/// Calculates the centroid (the center of mass) of the area of a polygon, which unlike
+/// [`centroid`](crate::vector2::centroid) does not depend on how the vertices are spread along its border.
+/// `None` if the polygon has an area of `0.0`, e.g. if it has fewer than 3 vertices or they are all collinear.
+/// # Examples
+/// ```
+/// use cute_gorl::geometry::*;
+/// use cute_gorl::vector2::*;
+/// let triangle = [Vector2 { x: 0., y: 0. }, Vector2 { x: 3., y: 0. }, Vector2 { x: 0., y: 3. }];
+/// assert_eq!(polygon_centroid(&triangle), Some(Vector2 { x: 1., y: 1. }));
+/// assert_eq!(polygon_centroid(&triangle[..2]), None);
+/// ```
+pub fn polygon_centroid(polygon: &[Vector2]) -> Option<Vector2> {
+    let mut prev: &Vector2 = polygon.last()?;
+    let mut twice_area: f64 = 0.0;
+    let mut weighted: Vector2 = Vector2 { x: 0., y: 0. };
+    for curr in polygon {
+        let cross: f64 = Vector2::perp_dot(prev, curr);
+        twice_area += cross;
+        weighted += (prev + curr) * cross;
+        prev = curr;
+    }
+    if twice_area == 0.0 { return None; }
+    Some(weighted / (3. * twice_area))
+}
+
+/// Calculates the point where the segment from `a1` to `a2` crosses the segment from `b1` to `b2`.
+/// Segments that only touch at an end point intersect there.
+/// `None` if the segments miss each other or are parallel, which includes collinear segments that overlap,
+/// as they have no single intersection point.
+/// # Examples
+/// ```
+/// use cute_gorl::geometry::*;
+/// use cute_gorl::vector2::*;
+/// let a1 = Vector2 { x: 0., y: 0. };
+/// let a2 = Vector2 { x: 4., y: 4. };
+/// let b1 = Vector2 { x: 0., y: 4. };
+/// let b2 = Vector2 { x: 4., y: 0. };
+/// assert_eq!(segment_intersection(&a1, &a2, &b1, &b2), Some(Vector2 { x: 2., y: 2. }));
+/// assert_eq!(segment_intersection(&a1, &b1, &a2, &b2), None);
+/// ```
+pub fn segment_intersection(a1: &Vector2, a2: &Vector2, b1: &Vector2, b2: &Vector2) -> Option<Vector2> {
+    let r: Vector2 = a2 - a1;
+    let s: Vector2 = b2 - b1;
+    let denom: f64 = Vector2::perp_dot(&r, &s);
+    if denom == 0.0 { return None; }
+    // solve a1 + r*t == b1 + s*u for the parameters along both segments
+    let offset: Vector2 = b1 - a1;
+    let t: f64 = Vector2::perp_dot(&offset, &s) / denom;
+    let u: f64 = Vector2::perp_dot(&offset, &r) / denom;
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) { Some(a1 + r * t) } else { None }
+}
+
+/// Walks along a polyline (an open path through `points`, not closed like a polygon) and returns the point
+/// `distance` away from its start, measured along the path. This moves at constant speed no matter how long the segments are.
+/// `None` if `distance` is negative or greater than the total length of the polyline, or there are no points.
+/// # Examples
+/// ```
+/// use cute_gorl::geometry::*;
+/// use cute_gorl::vector2::*;
+/// let path = [Vector2 { x: 0., y: 0. }, Vector2 { x: 4., y: 0. }, Vector2 { x: 4., y: 1. }];
+/// assert_eq!(polyline_point_at_distance(&path, 2.), Some(Vector2 { x: 2., y: 0. }));
+/// assert_eq!(polyline_point_at_distance(&path, 5.), Some(Vector2 { x: 4., y: 1. }));
+/// assert_eq!(polyline_point_at_distance(&path, 6.), None);
+/// ```
+pub fn polyline_point_at_distance(points: &[Vector2], distance: f64) -> Option<Vector2> {
+    if distance < 0.0 { return None; }
+    let mut remaining: f64 = distance;
+    for segment in points.windows(2) {
+        let len: f64 = Vector2::dist(&segment[0], &segment[1]);
+        if remaining <= len {
+            if len == 0.0 { return Some(segment[0]); }
+            return Some(Vector2::lerp(&segment[0], &segment[1], remaining / len));
+        }
+        remaining -= len;
+    }
+    match points {
+        [only] if distance == 0.0 => Some(*only),
+        _ => None
+    }
+}
+
+/// Determines whether or not the vertices of a polygon are in counter-clockwise order, i.e. its signed area is positive.
+/// Degenerate polygons without area are neither, and count as not counter-clockwise.
+/// # Examples
+/// ```
+/// use cute_gorl::geometry::*;
+/// use cute_gorl::vector2::*;
+/// let mut triangle = vec![Vector2 { x: 0., y: 0. }, Vector2 { x: 1., y: 0. }, Vector2 { x: 0., y: 1. }];
+/// assert!(polygon_is_ccw(&triangle));
+/// reverse_winding(&mut triangle);
+/// assert!( !(polygon_is_ccw(&triangle)) );
+/// ```
+#[inline]
+pub fn polygon_is_ccw(polygon: &[Vector2]) -> bool {
+    polygon_signed_area(polygon) > 0.0
+}
+
+/// Flips the winding order of a polygon in place, turning clockwise vertices counter-clockwise and vice versa.
+/// The vertices are simply reversed, so the first vertex becomes the last one.
+/// # Examples
+/// ```
+/// use cute_gorl::geometry::*;
+/// use cute_gorl::vector2::*;
+/// let mut triangle = [Vector2 { x: 0., y: 0. }, Vector2 { x: 0., y: 2. }, Vector2 { x: 3., y: 0. }];
+/// assert!( !(polygon_is_ccw(&triangle)) );
+/// reverse_winding(&mut triangle);
+/// assert!(polygon_is_ccw(&triangle));
+/// assert_eq!(triangle[0], Vector2 { x: 3., y: 0. });
+/// ```
+#[inline]
+pub fn reverse_winding(polygon: &mut [Vector2]) {
+    polygon.reverse();
+}
+
+/// Splits a simple polygon into triangles by ear clipping, returning them as triples of indices into `polygon`.
+/// Every triangle is in counter-clockwise order, whatever the winding of the input is, and a polygon with `n >= 3`
+/// vertices always gives `n - 2` triangles. Self-intersecting polygons are not supported:
+/// they still produce `n - 2` triangles, but these do not cover the polygon in any meaningful way.
+/// Takes `O(n³)` time in the worst case, which is fine for shapes but not for huge polygons.
+/// # Examples
+/// ```
+/// use cute_gorl::geometry::*;
+/// use cute_gorl::vector2::*;
+/// let square = [
+///     Vector2 { x: 0., y: 0. },
+///     Vector2 { x: 1., y: 0. },
+///     Vector2 { x: 1., y: 1. },
+///     Vector2 { x: 0., y: 1. }
+/// ];
+/// assert_eq!(triangulate(&square), vec![[3, 0, 1], [1, 2, 3]]);
+/// ```
+pub fn triangulate(polygon: &[Vector2]) -> Vec<[usize; 3]> {
+    let n: usize = polygon.len();
+    if n < 3 { return Vec::new(); }
+    let mut remaining: Vec<usize> = (0..n).collect();
+    // clipping only works on counter-clockwise polygons
+    if polygon_signed_area(polygon) < 0.0 { remaining.reverse(); }
+    let mut triangles: Vec<[usize; 3]> = Vec::with_capacity(n - 2);
+
+    while remaining.len() > 3 {
+        let m: usize = remaining.len();
+        let corner = |i: usize| [remaining[(i + m - 1) % m], remaining[i], remaining[(i + 1) % m]];
+        let is_ear = |i: usize| {
+            let [a, b, c] = corner(i).map(|k| &polygon[k]);
+            // an ear is a convex corner with no other vertex inside of it
+            turn(a, b, c) > 0.0 && !remaining.iter().any(|&k| {
+                let p = &polygon[k];
+                p != a && p != b && p != c && turn(a, b, p) >= 0.0 && turn(b, c, p) >= 0.0 && turn(c, a, p) >= 0.0
+            })
+        };
+        // without an ear the polygon is degenerate or self-intersecting; clip any corner to keep going
+        let ear: usize = (0..m).find(|&i| is_ear(i)).unwrap_or(0);
+        triangles.push(corner(ear));
+        remaining.remove(ear);
+    }
+    triangles.push([remaining[0], remaining[1], remaining[2]]);
+    triangles
+}
@@ -1,14 +1,38 @@
+// most of the vector code predates the owned operator impls and borrows its operands on purpose
+#![allow(clippy::op_ref)]
 mod local_tests;
 pub mod vector2;
 pub mod vector3;
+pub mod matrix2;
+pub mod matrix3;
+pub mod matrix4;
+pub mod aabb;
+pub mod ray;
+pub mod batch;
+pub mod geometry;
+pub mod angle;
+pub mod scalar;
 
-pub mod cute {
-    pub fn cuter()->i32{
-        println!("gwa");
-        4
-    }
+/// The version of cute_gorl this was compiled against, e.g. `"0.1.0"`.
+/// # Examples
+/// ```
+/// assert!(!cute_gorl::version().is_empty());
+/// ```
+pub fn version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
 }
+pub use math::EPSILON;
 mod math {
+    /// The default tolerance used by floating-point comparisons such as [`is_normalized`](crate::vector2::Vector2::is_normalized).
     pub const EPSILON: f64 = 1e-8;
-    pub fn cosq(sin_a: f64) -> f64 { (1.0 - sin_a*sin_a).sqrt() }
+    /// Advances a splitmix64 state and returns the next pseudo-random value.
+    pub fn splitmix64(state: &mut u64) -> u64 {
+        *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z: u64 = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    /// Maps pseudo-random bits onto `[-1, 1)`.
+    pub fn signed_unit(bits: u64) -> f64 { (bits >> 11) as f64 / (1_u64 << 52) as f64 - 1.0 }
 }
\ No newline at end of file
@@ -3,6 +3,14 @@ pub mod tests {
 
     use crate::vector2::*;
     use crate::vector3::*;  
+    use crate::matrix2::*;
+    use crate::matrix3::*;
+    use crate::matrix4::*;
+    use crate::aabb::*;
+    use crate::ray::*;
+    use crate::batch::*;
+    use crate::geometry::*;
+    use crate::angle::*;
 
     #[test]
     pub fn gwa(){
@@ -21,4 +29,1190 @@ pub mod tests {
         assert!(!( Vector3{ x: 0.0001, y: 0., z: 0. }.is_nullvector() ));
 
     }
+    #[test]
+    pub fn ne_is_not_eq(){
+        // vectors differing in a single komponent are unequal
+        assert!(Vector2{ x: 1., y: 2. } != Vector2{ x: 1., y: 3. });
+        assert!(Vector3{ x: 1., y: 2., z: 3. } != Vector3{ x: 1., y: 2., z: 4. });
+        assert!(!( Vector3{ x: 1., y: 2., z: 3. } != Vector3{ x: 1., y: 2., z: 3. } ));
+    }
+    #[test]
+    pub fn reflect_sequence_corner(){
+        // two perpendicular surfaces reverse any direction lying in the plane of their normals
+        let v = Vector3{ x: 0.6, y: -0.8, z: 0. };
+        let normals = [
+            Vector3{ x: 1., y: 0., z: 0. },
+            Vector3{ x: 0., y: 1., z: 0. }
+        ];
+        assert_eq!(reflect_sequence(&v, &normals), -v);
+        assert_eq!(reflect_sequence(&v, &[]), v);
+    }
+    #[test]
+    pub fn move_towards(){
+        let start = Vector2{ x: 1., y: 1. };
+        let target = Vector2{ x: 1., y: 5. };
+        // undershoot: stops after max_delta
+        assert_eq!(start.move_towards(&target, 1.5), Vector2{ x: 1., y: 2.5 });
+        // overshoot: snaps onto the target instead of passing it
+        assert_eq!(start.move_towards(&target, 7.), target);
+        // exact arrival
+        assert_eq!(start.move_towards(&target, 4.), target);
+        // non-positive step leaves the point in place
+        assert_eq!(start.move_towards(&target, 0.), start);
+        assert_eq!(start.move_towards(&target, -1.), start);
+
+        let start = Vector3{ x: 0., y: 0., z: -2. };
+        let target = Vector3{ x: 0., y: 0., z: 2. };
+        assert_eq!(start.move_towards(&target, 1.), Vector3{ x: 0., y: 0., z: -1. });
+        assert_eq!(start.move_towards(&target, 5.), target);
+        assert_eq!(start.move_towards(&target, 4.), target);
+        assert_eq!(start.move_towards(&target, -1.), start);
+    }
+    #[test]
+    pub fn lerp_wrapped_across_boundary(){
+        let min = Vector3{ x: -5., y: -5., z: -5. };
+        let max = Vector3{ x: 5., y: 5., z: 5. };
+        let v1 = Vector3{ x: 4., y: 0., z: -4. };
+        let v2 = Vector3{ x: -4., y: 2., z: 4. };
+        // the naive lerp travels 8 units through the middle of the world
+        assert_eq!(Vector3::lerp(&v1, &v2, 0.25), Vector3{ x: 2., y: 0.5, z: -2. });
+        // the wrapped one travels 2 units across the edge
+        assert_eq!(
+            Vector3::lerp_wrapped(&v1, &v2, 0.25, &min, &max),
+            Vector3{ x: 4.5, y: 0.5, z: -4.5 }
+        );
+        assert_eq!(
+            Vector3::lerp_wrapped(&v1, &v2, 0.75, &min, &max),
+            Vector3{ x: -4.5, y: 1.5, z: 4.5 }
+        );
+    }
+    #[test]
+    pub fn matrix2_rotation(){
+        use std::f64::consts::PI;
+        let r = &Matrix2::rotation(0.5*PI) * &Vector2{ x: 1., y: 0. };
+        assert!(r.x.abs() < 1e-12 && (r.y - 1.).abs() < 1e-12);
+        // the inverse of a rotation is the reverse rotation, and composing them gives the identity
+        let inv = Matrix2::rotation(0.7).inverse().unwrap();
+        let back = Matrix2::rotation(-0.7);
+        let id = &Matrix2::rotation(0.7) * &back;
+        for i in 0..2 { for j in 0..2 {
+            assert!((inv.m[i][j] - back.m[i][j]).abs() < 1e-12);
+            assert!((id.m[i][j] - Matrix2::identity().m[i][j]).abs() < 1e-12);
+        }}
+    }
+    #[test]
+    pub fn grid_rounding(){
+        let v = Vector2{ x: -0.5, y: 1.5 };
+        assert_eq!(v.floor(), Vector2{ x: -1., y: 1. });
+        assert_eq!(v.ceil(), Vector2{ x: -0., y: 2. });
+        assert_eq!(v.round(), Vector2{ x: -1., y: 2. });
+        assert_eq!(Vector2{ x: -1.49, y: 0.49 }.round(), Vector2{ x: -1., y: 0. });
+
+        let v = Vector3{ x: -2.5, y: 0.5, z: -0.2 };
+        assert_eq!(v.floor(), Vector3{ x: -3., y: 0., z: -1. });
+        assert_eq!(v.ceil(), Vector3{ x: -2., y: 1., z: -0. });
+        assert_eq!(v.round(), Vector3{ x: -3., y: 1., z: -0. });
+    }
+    #[test]
+    pub fn reflect_incidence_angles(){
+        let h = 0.5_f64.sqrt();
+        let n = Vector2{ x: 0., y: 1. };
+        // (incoming, expected outgoing) at 0°, 45° and 90° (grazing) incidence
+        let cases = [
+            (Vector2{ x: 0., y: -1. }, Vector2{ x: 0., y: 1. }),
+            (Vector2{ x: h, y: -h }, Vector2{ x: h, y: h }),
+            (Vector2{ x: 1., y: 0. }, Vector2{ x: 1., y: 0. })
+        ];
+        for (v, expected) in cases {
+            let r = v.reflect(&n);
+            assert!(Vector2::dist(&r, &expected) < 1e-12);
+            assert!((r.magn() - v.magn()).abs() < 1e-12);
+        }
+
+        let n = Vector3{ x: 0., y: 0., z: 1. };
+        let cases = [
+            (Vector3{ x: 0., y: 0., z: -1. }, Vector3{ x: 0., y: 0., z: 1. }),
+            (Vector3{ x: 0., y: h, z: -h }, Vector3{ x: 0., y: h, z: h }),
+            (Vector3{ x: h, y: h, z: 0. }, Vector3{ x: h, y: h, z: 0. })
+        ];
+        for (v, expected) in cases {
+            let mut r = v;
+            r.reflect(&n);
+            assert!(Vector3::dist(&r, &expected) < 1e-12);
+            assert!((r.magn() - v.magn()).abs() < 1e-12);
+        }
+    }
+    #[test]
+    pub fn aabb_touching_edges(){
+        let b = Aabb2::new(Vector2{ x: 0., y: 0. }, Vector2{ x: 1., y: 1. });
+        // sharing an edge or only a corner still counts as intersecting
+        let edge = Aabb2::new(Vector2{ x: 1., y: 0.25 }, Vector2{ x: 2., y: 0.75 });
+        let corner = Aabb2::new(Vector2{ x: 1., y: 1. }, Vector2{ x: 2., y: 2. });
+        let apart = Aabb2::new(Vector2{ x: 1.0001, y: 0. }, Vector2{ x: 2., y: 1. });
+        assert!(b.intersects(&edge) && edge.intersects(&b));
+        assert!(b.intersects(&corner) && corner.intersects(&b));
+        assert!(!b.intersects(&apart) && !apart.intersects(&b));
+        assert!(b.contains(&Vector2{ x: 1., y: 0.5 }));
+
+        let b = Aabb3::new(Vector3{ x: 0., y: 0., z: 0. }, Vector3{ x: 1., y: 1., z: 1. });
+        let face = Aabb3::new(Vector3{ x: 0., y: 0., z: 1. }, Vector3{ x: 1., y: 1., z: 2. });
+        let apart = Aabb3::new(Vector3{ x: 0., y: 0., z: 1.0001 }, Vector3{ x: 1., y: 1., z: 2. });
+        assert!(b.intersects(&face) && face.intersects(&b));
+        assert!(!b.intersects(&apart) && !apart.intersects(&b));
+        assert!(b.contains(&Vector3{ x: 0.5, y: 0.5, z: 1. }));
+    }
+    #[test]
+    pub fn unit_cube_volume(){
+        let points: Vec<Vector3> = (0..8).map(|i| Vector3{
+            x: (i & 1) as f64,
+            y: ((i >> 1) & 1) as f64,
+            z: ((i >> 2) & 1) as f64
+        }).collect();
+        // two triangles per face, wound counter-clockwise seen from outside
+        let faces = [
+            [0, 2, 3], [0, 3, 1], // z = 0
+            [4, 5, 7], [4, 7, 6], // z = 1
+            [0, 1, 5], [0, 5, 4], // y = 0
+            [2, 6, 7], [2, 7, 3], // y = 1
+            [0, 4, 6], [0, 6, 2], // x = 0
+            [1, 3, 7], [1, 7, 5]  // x = 1
+        ];
+        assert!((convex_volume(&points, &faces) - 1.).abs() < 1e-12);
+        let c = convex_centroid(&points, &faces).unwrap();
+        assert!(Vector3::dist(&c, &Vector3{ x: 0.5, y: 0.5, z: 0.5 }) < 1e-12);
+
+        // the result must not depend on where the origin sits relative to the mesh
+        let offset = Vector3{ x: 10., y: -3., z: 7. };
+        let moved: Vec<Vector3> = points.iter().map(|p| p + &offset).collect();
+        assert!((convex_volume(&moved, &faces) - 1.).abs() < 1e-9);
+
+        let flipped: Vec<[usize; 3]> = faces.iter().map(|[a, b, c]| [*a, *c, *b]).collect();
+        assert!((convex_volume(&points, &flipped) + 1.).abs() < 1e-12);
+    }
+    #[test]
+    pub fn ray_unit_sphere(){
+        let origin = Vector3{ x: 0., y: 0., z: 0. };
+        let dir = Vector3{ x: 1., y: 0., z: 0. };
+        // straight through the middle
+        let hit = Ray3::new(Vector3{ x: -3., y: 0., z: 0. }, dir);
+        assert_eq!(hit.intersect_sphere(&origin, 1.), Some(2.));
+        // from inside, the ray leaves the sphere
+        let inside = Ray3::new(Vector3{ x: 0.5, y: 0., z: 0. }, dir);
+        assert_eq!(inside.intersect_sphere(&origin, 1.), Some(0.5));
+        // grazing the top of the sphere touches it exactly once
+        let graze = Ray3::new(Vector3{ x: -3., y: 1., z: 0. }, dir);
+        assert_eq!(graze.intersect_sphere(&origin, 1.), Some(3.));
+        assert_eq!(graze.at(3.), Vector3{ x: 0., y: 1., z: 0. });
+        // passing above it or pointing away misses
+        let miss = Ray3::new(Vector3{ x: -3., y: 1.01, z: 0. }, dir);
+        assert_eq!(miss.intersect_sphere(&origin, 1.), None);
+        let behind = Ray3::new(Vector3{ x: 3., y: 0., z: 0. }, dir);
+        assert_eq!(behind.intersect_sphere(&origin, 1.), None);
+        // a ray parallel to a plane never reaches it
+        assert_eq!(hit.intersect_plane(&origin, &Vector3{ x: 0., y: 1., z: 0. }), None);
+    }
+    #[test]
+    pub fn angle_info_consistent(){
+        let v1 = Vector2{ x: 2., y: 1. };
+        for i in 0..16 {
+            let v2 = v1.rotate(0.3 + 0.4 * i as f64);
+            let info = Vector2::angle_info(&v1, &v2);
+            assert!((info.degrees - info.radians.to_degrees()).abs() < 1e-9);
+            assert_eq!(info.radians, info.signed.abs());
+            assert!(info.radians >= 0. && info.radians <= std::f64::consts::PI);
+            // swapping the arguments flips only the sign
+            let swapped = Vector2::angle_info(&v2, &v1);
+            assert!((swapped.signed + info.signed).abs() < 1e-12);
+            assert_eq!(swapped.radians, info.radians);
+        }
+    }
+    #[test]
+    pub fn fresnel_schlick(){
+        let normal = Vector3{ x: 0., y: 1., z: 0. };
+        // head-on most of the light passes into glass
+        let head_on = Vector3{ x: 0., y: -1., z: 0. };
+        assert!((fresnel(&head_on, &normal, 1. / 1.5) - 0.04).abs() < 1e-12);
+        // media with the same index reflect nothing
+        assert_eq!(fresnel(&head_on, &normal, 1.), 0.);
+        // at grazing incidence everything gets reflected
+        let grazing = Vector3{ x: 1., y: 0., z: 0. };
+        assert_eq!(fresnel(&grazing, &normal, 1. / 1.5), 1.);
+        // and in between the reflectance rises monotonically
+        let mut last = 0.;
+        for i in 0..=10 {
+            let a = 0.15 * i as f64;
+            let r = fresnel(&Vector3{ x: a.sin(), y: -a.cos(), z: 0. }, &normal, 1. / 1.5);
+            assert!(r >= last && r <= 1.);
+            last = r;
+        }
+        // leaving glass head-on reflects just as much as entering it
+        assert!((fresnel(&head_on, &normal, 1.5) - 0.04).abs() < 1e-12);
+        // but past the critical angle of about 41.8 degrees all of the light is reflected
+        let steep = Vector3{ x: 0.7, y: -(0.51f64).sqrt(), z: 0. };
+        assert_eq!(steep.refract(&normal, 1.5), None);
+        assert_eq!(fresnel(&steep, &normal, 1.5), 1.);
+        let shallow = Vector3{ x: 0.6, y: -0.8, z: 0. };
+        assert!(shallow.refract(&normal, 1.5).is_some());
+        assert!(fresnel(&shallow, &normal, 1.5) < 1.);
+    }
+    #[test]
+    pub fn clamp_length_with_direction_over_max(){
+        let v = Vector3{ x: 0., y: 30., z: 40. };
+        let (clamped, dir) = v.clamp_length_with_direction(1., 10.);
+        assert!(clamped.approx_eq_default(&Vector3{ x: 0., y: 6., z: 8. }));
+        assert!(dir.approx_eq_default(&Vector3{ x: 0., y: 0.6, z: 0.8 }));
+        assert!(clamped.approx_eq_default(&v.clamp_length(1., 10.)));
+        let (clamped, dir) = Vector3{ x: 0., y: 0., z: 0. }.clamp_length_with_direction(1., 10.);
+        assert!(clamped.is_nullvector() && dir.is_nullvector());
+
+        let v = Vector2{ x: -30., y: 40. };
+        let (clamped, dir) = v.clamp_length_with_direction(1., 10.);
+        assert!(clamped.approx_eq_default(&Vector2{ x: -6., y: 8. }));
+        assert!(dir.approx_eq_default(&Vector2{ x: -0.6, y: 0.8 }));
+    }
+    #[test]
+    pub fn fit_plane_near_planar(){
+        let mut normal = Vector3{ x: 1., y: 2., z: 2. };
+        normal.normalize();
+        let center = Vector3{ x: 3., y: -1., z: 4. };
+        let u = Vector3::crossp(&normal, &Vector3{ x: 0., y: 0., z: 1. });
+        let w = Vector3::crossp(&normal, &u);
+        // a grid on the plane with a little deterministic noise along the normal
+        let mut points = Vec::new();
+        for i in -3..=3_i32 {
+            for j in -3..=3 {
+                let noise = 0.001 * ((i * 7 + j * 13).rem_euclid(5) as f64 - 2.);
+                let offset = &(&(&u * i as f64) + &(&w * j as f64)) + &(&normal * noise);
+                points.push(&center + &offset);
+            }
+        }
+        let (p, n) = fit_plane(&points).unwrap();
+        assert!(Vector3::dist(&p, &center) < 2e-3);
+        assert!(n.is_normalized());
+        assert!(Vector3::scalar(&n, &normal).abs() > 1. - 1e-5);
+
+        assert_eq!(fit_plane(&points[..2]), None);
+    }
+    #[test]
+    pub fn is_collinear_computed(){
+        // a scalar multiple that went through arithmetic picks up rounding errors
+        let v1 = Vector3{ x: 0.1, y: 0.7, z: 0.3 };
+        let v2 = &(&v1 / 3.) * 7.;
+        assert!(!Vector3::is_collinear(&v1, &v2));
+        assert!(Vector3::is_collinear_eps(&v1, &v2, crate::EPSILON));
+        assert!(Vector3::is_collinear_eps(&v1, &-v2, crate::EPSILON));
+        assert!(!Vector3::is_collinear_eps(&v1, &Vector3{ x: 0.1, y: 0.7, z: 0.31 }, crate::EPSILON));
+
+        let v1 = Vector2{ x: 0.1, y: 0.7 };
+        let v2 = &(&v1 / 3.) * 7.3;
+        assert!(!Vector2::is_collinear(&v1, &v2));
+        assert!(Vector2::is_collinear_eps(&v1, &v2, crate::EPSILON));
+        // the test is relative, so it holds the same for tiny and huge vectors
+        assert!(Vector2::is_collinear_eps(&(&v1 * 1e-12), &(&v2 * 1e12), crate::EPSILON));
+        assert!(!Vector2::is_collinear_eps(&v1, &v1.rotate_right(), crate::EPSILON));
+    }
+    #[test]
+    pub fn debug_format(){
+        let v = Vector3{ x: 1., y: 2.5, z: -3. };
+        assert_eq!(format!("{:?}", v), "Vec3(1, 2.5, -3)");
+        assert_eq!(format!("{:#?}", v), "Vector3 {\n    x: 1.0,\n    y: 2.5,\n    z: -3.0,\n}");
+        let v = Vector2{ x: 0.5, y: 4. };
+        assert_eq!(format!("{:?}", v), "Vec2(0.5, 4)");
+        assert_eq!(format!("{:.1?}", v), "Vec2(0.5, 4.0)");
+        assert_eq!(format!("{:5?}", v), "Vec2(  0.5,     4)");
+        assert_eq!(format!("{:.1?}", Vector3{ x: 1.25, y: -2., z: 1. / 3. }), "Vec3(1.2, -2.0, 0.3)");
+        assert_eq!(format!("{:#?}", v), "Vector2 {\n    x: 0.5,\n    y: 4.0,\n}");
+    }
+    #[test]
+    pub fn owned_operators(){
+        let a = Vector3{ x: 1., y: 2., z: 3. };
+        let b = Vector3{ x: 0.5, y: -1., z: 2. };
+        let (ra, rb) = (&a, &b);
+        assert_eq!(a + b, Vector3{ x: 1.5, y: 1., z: 5. });
+        assert_eq!(a - b, Vector3{ x: 0.5, y: 3., z: 1. });
+        assert_eq!(2.0 * a, Vector3{ x: 2., y: 4., z: 6. });
+        assert_eq!(a * 2.0 - b, a + (a - b));
+        // mixed owned and borrowed operands agree with the borrowed-only impls
+        assert_eq!(ra + b, ra + rb);
+        assert_eq!(a + rb, ra + rb);
+        assert_eq!(ra - b, a - rb);
+        assert_eq!((a + b) / 2., Vector3::lerp(&a, &b, 0.5));
+
+        let a = Vector2{ x: 1., y: 2. };
+        let b = Vector2{ x: 0.5, y: -1. };
+        let (ra, rb) = (&a, &b);
+        assert_eq!(a + b, Vector2{ x: 1.5, y: 1. });
+        assert_eq!(a - b, Vector2{ x: 0.5, y: 3. });
+        assert_eq!(2.0 * a, Vector2{ x: 2., y: 4. });
+        assert_eq!(ra + b, a + rb);
+        assert_eq!(ra - b, ra - rb);
+        assert_eq!(a * 0.5, a / 2.);
+    }
+    #[test]
+    pub fn cross_stable_nearly_parallel(){
+        let a = 1. + 2_f64.powi(-27);
+        let b = 1. + 2_f64.powi(-26);
+        let v1 = Vector3{ x: a, y: b, z: 0. };
+        let v2 = Vector3{ x: b, y: a, z: 0. };
+        // a*a - b*b worked out by hand: -2^-26 - 2^-52 + 2^-54
+        let exact = -2_f64.powi(-26) - 3. * 2_f64.powi(-54);
+        let fast = Vector3::crossp(&v1, &v2).z;
+        let stable = Vector3::cross_stable(&v1, &v2).z;
+        assert_eq!(stable, exact);
+        assert!((stable - exact).abs() < (fast - exact).abs());
+    }
+    #[test]
+    pub fn sum_vectors(){
+        let points = [
+            Vector3{ x: 1., y: 2., z: 3. },
+            Vector3{ x: -4., y: 0.5, z: 1. },
+            Vector3{ x: 0.25, y: 0., z: -2. }
+        ];
+        let manual = points[0] + points[1] + points[2];
+        assert_eq!(points.iter().sum::<Vector3>(), manual);
+        assert_eq!(points.into_iter().sum::<Vector3>(), manual);
+        assert_eq!(Vec::<Vector3>::new().into_iter().sum::<Vector3>(), Vector3{ x: 0., y: 0., z: 0. });
+
+        let points = [Vector2{ x: 1., y: 2. }, Vector2{ x: -3., y: 0.5 }];
+        assert_eq!(points.iter().sum::<Vector2>(), points[0] + points[1]);
+        assert_eq!(points.into_iter().sum::<Vector2>(), Vector2{ x: -2., y: 2.5 });
+    }
+    #[test]
+    pub fn jitter_deterministic(){
+        let v = Vector3{ x: 10., y: -5., z: 0. };
+        let first: Vec<Vector3> = (0..32).map(|seed| v.jitter(seed, 0.25)).collect();
+        let second: Vec<Vector3> = (0..32).map(|seed| v.jitter(seed, 0.25)).collect();
+        assert_eq!(first, second);
+        for j in &first {
+            let d = (j - v).abs();
+            assert!(d.x <= 0.25 && d.y <= 0.25 && d.z <= 0.25);
+        }
+        // different seeds scatter differently
+        assert_ne!(first[0], first[1]);
+        assert_eq!(v.jitter(7, 0.), v);
+
+        let v = Vector2{ x: 1., y: 1. };
+        assert_eq!(v.jitter(123, 2.), v.jitter(123, 2.));
+        assert_ne!(v.jitter(123, 2.), v.jitter(124, 2.));
+    }
+    #[test]
+    pub fn hashable_snapped(){
+        use std::collections::HashSet;
+        let raw = [
+            Vector2{ x: 0.9, y: 2.2 },
+            Vector2{ x: 1.1, y: 1.8 },
+            Vector2{ x: 3.4, y: -0.6 },
+            Vector2{ x: 2.6, y: -1.4 }
+        ];
+        let set: HashSet<HashableVector2> = raw.iter().map(|v| HashableVector2(v.round())).collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Vector2{ x: 1., y: 2. }.into()));
+        assert!(set.contains(&Vector2{ x: 3., y: -1. }.into()));
+        // bitwise semantics
+        assert_ne!(HashableVector2(Vector2{ x: 0., y: 0. }), HashableVector2(Vector2{ x: -0., y: 0. }));
+        let nan = HashableVector3(Vector3{ x: f64::NAN, y: 0., z: 0. });
+        assert_eq!(nan, nan);
+    }
+    #[test]
+    pub fn obj_round_trip(){
+        let points = vec![
+            Vector3{ x: 0.1, y: -2., z: 1e-12 },
+            Vector3{ x: 1234.5678, y: 0., z: -0.3333333333333333 },
+            Vector3{ x: 1., y: 1., z: 1. }
+        ];
+        let text = to_obj_vertices(&points);
+        assert_eq!(text.lines().count(), 3);
+        assert_eq!(from_obj_vertices(&text), points);
+        // unrelated and broken lines are skipped
+        let noisy = format!("o thing\n{}vt 0.5 0.5\nv 1 2\nf 1 2 3\n", text);
+        assert_eq!(from_obj_vertices(&noisy), points);
+    }
+    #[test]
+    pub fn lerp_clamped_vs_unclamped(){
+        let a = Vector2{ x: 0., y: 0. };
+        let b = Vector2{ x: 2., y: 4. };
+        assert_eq!(Vector2::lerp(&a, &b, -0.5), Vector2{ x: -1., y: -2. });
+        assert_eq!(Vector2::lerp(&a, &b, 1.5), Vector2{ x: 3., y: 6. });
+        assert_eq!(Vector2::lerp_clamped(&a, &b, -0.5), a);
+        assert_eq!(Vector2::lerp_clamped(&a, &b, 1.5), b);
+        assert_eq!(Vector2::lerp_clamped(&a, &b, 0.25), Vector2::lerp(&a, &b, 0.25));
+
+        let a = Vector3{ x: 1., y: 0., z: -2. };
+        let b = Vector3{ x: 3., y: 2., z: 2. };
+        assert_eq!(Vector3::lerp(&a, &b, -0.5), Vector3{ x: 0., y: -1., z: -4. });
+        assert_eq!(Vector3::lerp(&a, &b, 1.5), Vector3{ x: 4., y: 3., z: 4. });
+        assert_eq!(Vector3::lerp_clamped(&a, &b, -0.5), a);
+        assert_eq!(Vector3::lerp_clamped(&a, &b, 1.5), b);
+    }
+    #[test]
+    pub fn refract_snell(){
+        let n = Vector3{ x: 0., y: 1., z: 0. };
+        let mut v = Vector3f64{ x: 1., y: -1., z: 0. };
+        v.normalize();
+        // same medium on both sides: the ray passes straight through
+        let through = v.refract(&n, 1.).unwrap();
+        assert!(through.approx_eq_default(&v));
+        // into a denser medium the ray bends towards the normal, keeping sin ratios
+        let bent = v.refract(&n, 1. / 1.5).unwrap();
+        assert!(bent.is_normalized());
+        assert!((bent.x - v.x / 1.5).abs() < 1e-12 && bent.y < 0.);
+        // from glass into air at 45° exceeds the critical angle (~41.8°)
+        assert_eq!(v.refract(&n, 1.5), None);
+    }
+    #[test]
+    pub fn dist_to_segment_cases(){
+        let a = Vector2{ x: 1., y: 1. };
+        let b = Vector2{ x: 5., y: 1. };
+        // the perpendicular foot lies on the segment
+        assert_eq!(Vector2{ x: 3., y: 4. }.dist_to_segment(&a, &b), 3.);
+        // clamped to either endpoint
+        assert_eq!(Vector2{ x: -2., y: 5. }.dist_to_segment(&a, &b), 5.);
+        assert_eq!(Vector2{ x: 8., y: -3. }.dist_to_segment(&a, &b), 5.);
+        // the infinite line does not clamp
+        assert_eq!(Vector2{ x: -2., y: 5. }.dist_to_line(&a, &b), 4.);
+        assert_eq!(Vector2{ x: 8., y: -3. }.dist_to_line(&b, &a), 4.);
+        // degenerate segment
+        let p = Vector2{ x: 4., y: 5. };
+        assert_eq!(p.dist_to_segment(&a, &a), 5.);
+        assert_eq!(p.dist_to_line(&a, &a), 5.);
+    }
+    #[test]
+    pub fn barycentric_weights(){
+        let a = Vector2{ x: -1., y: 0. };
+        let b = Vector2{ x: 3., y: 1. };
+        let c = Vector2{ x: 0., y: 5. };
+        let close = |(u, v, w): (f64, f64, f64), (eu, ev, ew): (f64, f64, f64)|
+            (u - eu).abs() < 1e-12 && (v - ev).abs() < 1e-12 && (w - ew).abs() < 1e-12;
+        // each vertex gets all of the weight
+        assert!(close(Vector2::barycentric(&a, &a, &b, &c), (1., 0., 0.)));
+        assert!(close(Vector2::barycentric(&b, &a, &b, &c), (0., 1., 0.)));
+        assert!(close(Vector2::barycentric(&c, &a, &b, &c), (0., 0., 1.)));
+        // the centroid weights all vertices equally, whichever way the triangle is wound
+        let centroid = (a + b + c) / 3.;
+        let third = 1. / 3.;
+        assert!(close(Vector2::barycentric(&centroid, &a, &b, &c), (third, third, third)));
+        assert!(close(Vector2::barycentric(&centroid, &a, &c, &b), (third, third, third)));
+        assert_eq!(Vector2::triangle_area(&a, &b, &c), 9.5);
+    }
+    #[test]
+    pub fn matrix3_rotate_then_translate(){
+        let angle: f64 = 0.6;
+        let offset = Vector2{ x: 3., y: -2. };
+        let m = &Matrix3::from_translation(offset) * &Matrix3::from_rotation(angle);
+        let p = Vector2{ x: 1.5, y: 0.5 };
+        let manual = Vector2{
+            x: p.x * angle.cos() - p.y * angle.sin() + offset.x,
+            y: p.x * angle.sin() + p.y * angle.cos() + offset.y
+        };
+        assert!(m.transform_point(&p).approx_eq(&manual, 1e-12));
+        // directions only get rotated
+        assert!(m.transform_vector(&p).approx_eq(&(manual - offset), 1e-12));
+        // the other order moves the point first
+        let other = &Matrix3::from_rotation(angle) * &Matrix3::from_translation(offset);
+        let manual = Matrix3::from_rotation(angle).transform_point(&(p + offset));
+        assert!(other.transform_point(&p).approx_eq(&manual, 1e-12));
+        assert_eq!(&Matrix3::identity() * &m, m);
+    }
+    #[test]
+    pub fn matrix4_look_at(){
+        let eye = Vector3{ x: 3., y: 2., z: -4. };
+        let target = Vector3{ x: -1., y: 0.5, z: 2. };
+        let up = Vector3{ x: 0., y: 1., z: 0. };
+        let view = Matrix4::look_at(&eye, &target, &up);
+        // the camera sits at the origin and sees the target straight ahead along -z
+        let dist = Vector3::dist(&eye, &target);
+        assert!(view.transform_point(&eye).approx_eq(&Vector3{ x: 0., y: 0., z: 0. }, 1e-12));
+        assert!(view.transform_point(&target).approx_eq(&Vector3{ x: 0., y: 0., z: -dist }, 1e-12));
+        let mut dir = view.transform_direction(&(target - eye));
+        dir.normalize();
+        assert!(dir.approx_eq(&Vector3{ x: 0., y: 0., z: -1. }, 1e-12));
+        // up stays up
+        assert!(view.transform_direction(&up).y > 0.);
+
+        // axis-angle agrees with Vector3::rotate, and composing applies the right-hand matrix first
+        let mut axis = Vector3{ x: 1., y: -1., z: 1. };
+        axis.normalize();
+        let p = Vector3{ x: 1., y: 1., z: 1. };
+        let r = Matrix4::from_axis_angle(axis, 0.5 * std::f64::consts::PI);
+        assert!(r.transform_point(&p).approx_eq(&p.rotate(0.5 * std::f64::consts::PI, &axis), 1e-12));
+        let t = Matrix4::from_translation(Vector3{ x: 0., y: 0., z: 10. });
+        let tr = &t * &r;
+        assert!(tr.transform_point(&p).approx_eq(&(r.transform_point(&p) + Vector3{ x: 0., y: 0., z: 10. }), 1e-12));
+    }
+    #[test]
+    pub fn batch_matches_per_element(){
+        let base: Vec<Vector3> = (0..11).map(|i| {
+            let f = i as f64;
+            Vector3{ x: f.sin() + 2., y: f.cos(), z: 0.3 * f }
+        }).collect();
+        let normalized: Vec<Vector3> = base.iter().map(|b| { let mut n = *b; n.normalize(); n }).collect();
+        let mut v = base.clone();
+        normalize_slice(&mut v);
+        assert_eq!(v, normalized);
+        add_slice(&mut v, &base);
+        for ((sum, n), b) in v.iter().zip(&normalized).zip(&base) {
+            assert_eq!(*sum, n + b);
+        }
+    }
+    #[test]
+    pub fn clamp_thresholds(){
+        // below, at and above the threshold
+        let (below, at, above) = (Vector2{ x: 0.6, y: 0.8 }, Vector2{ x: 3., y: 4. }, Vector2{ x: 6., y: 8. });
+        let clamped_max = |mut v: Vector2| { v.clamp_max(5.); v };
+        let clamped_min = |mut v: Vector2| { v.clamp_min(5.); v };
+        assert_eq!(clamped_max(below), below);
+        assert_eq!(clamped_max(at), at);
+        assert_eq!(clamped_max(above), at);
+        assert_eq!(clamped_min(below), at);
+        assert_eq!(clamped_min(at), at);
+        assert_eq!(clamped_min(above), above);
+
+        let (below, at, above) = (
+            Vector3{ x: 0., y: 0.6, z: 0.8 },
+            Vector3{ x: 0., y: 3., z: 4. },
+            Vector3{ x: 0., y: 6., z: 8. }
+        );
+        let clamped_max = |mut v: Vector3| { v.clamp_max(5.); v };
+        let clamped_min = |mut v: Vector3| { v.clamp_min(5.); v };
+        assert_eq!(clamped_max(below), below);
+        assert_eq!(clamped_max(at), at);
+        assert_eq!(clamped_max(above), at);
+        assert_eq!(clamped_min(below), at);
+        assert_eq!(clamped_min(at), at);
+        assert_eq!(clamped_min(above), above);
+    }
+    #[test]
+    pub fn finite_and_nan(){
+        let inf = f64::INFINITY;
+        let nan = f64::NAN;
+        assert!(Vector2{ x: 1., y: 2. }.is_finite());
+        assert!(!Vector2{ x: inf, y: 2. }.is_finite());
+        assert!(!Vector2{ x: 1., y: nan }.is_finite());
+        assert!(!Vector2{ x: inf, y: -inf }.is_nan());
+        assert!(Vector2{ x: nan, y: 2. }.is_nan());
+
+        assert!(Vector3{ x: 1., y: 2., z: 3. }.is_finite());
+        assert!(!Vector3{ x: 1., y: -inf, z: 3. }.is_finite());
+        assert!(!Vector3{ x: 1., y: 2., z: nan }.is_finite());
+        assert!(!Vector3{ x: inf, y: 2., z: 3. }.is_nan());
+        assert!(Vector3{ x: 1., y: 2., z: nan }.is_nan());
+
+        // a division by zero is exactly the kind of thing these should catch
+        let mut v = Vector3{ x: 0., y: 0., z: 0. };
+        v.normalize();
+        assert!(v.is_nan() && !v.is_finite());
+    }
+    #[test]
+    pub fn version(){
+        assert_eq!(crate::version(), env!("CARGO_PKG_VERSION"));
+        assert_eq!(crate::version().split('.').count(), 3);
+    }
+    #[test]
+    pub fn component_iteration(){
+        let v = Vector3{ x: 1.5, y: -2., z: 4.25 };
+        assert_eq!(v.into_iter().sum::<f64>(), v.x + v.y + v.z);
+        assert_eq!(v.as_slice().iter().sum::<f64>(), v.x + v.y + v.z);
+        assert_eq!(v.into_iter().collect::<Vec<f64>>(), v.as_slice());
+
+        let v = Vector2{ x: 1.5, y: -2. };
+        assert_eq!(v.into_iter().sum::<f64>(), v.x + v.y);
+        assert_eq!(v.as_slice().iter().sum::<f64>(), v.x + v.y);
+    }
+    #[test]
+    pub fn midpoint_is_lerp_half(){
+        let (a, b) = (Vector2{ x: 0.3, y: -1.7 }, Vector2{ x: 2.9, y: 0.1 });
+        assert_eq!(Vector2::midpoint(&a, &b), Vector2::lerp(&a, &b, 0.5));
+        let (a, b) = (Vector3{ x: 0.3, y: -1.7, z: 5.5 }, Vector3{ x: 2.9, y: 0.1, z: -0.2 });
+        assert_eq!(Vector3::midpoint(&a, &b), Vector3::lerp(&a, &b, 0.5));
+    }
+    #[test]
+    pub fn rotate3_past_quarter_turn(){
+        use std::f64::consts::PI;
+        let z = Vector3{ x: 0., y: 0., z: 1. };
+        let v = Vector3{ x: 2., y: 1., z: 5. };
+        assert!(v.rotate(PI, &z).approx_eq(&Vector3{ x: -2., y: -1., z: 5. }, 1e-12));
+        let mut axis = Vector3{ x: 1., y: -2., z: 0.5 };
+        axis.normalize();
+        for angle in [0.4, 2.5, -2., 3.5] {
+            let m = Matrix4::from_axis_angle(axis, angle);
+            assert!(v.rotate(angle, &axis).approx_eq(&m.transform_direction(&v), 1e-12));
+        }
+    }
+    #[test]
+    pub fn rotate_past_quarter_turn(){
+        use std::f64::consts::PI;
+        let v = Vector2{ x: 2., y: 1. };
+        // the cosine used to be derived from the sine, which loses its sign beyond 90 degrees
+        assert!(v.rotate(PI).approx_eq(&-v, 1e-12));
+        assert!(v.rotate(0.75*PI).approx_eq(&v.rotate(0.25*PI).rotate_right(), 1e-12));
+        assert!(v.rotate(-0.5*PI).approx_eq(&v.rotate_left(), 1e-12));
+        let pivot = Vector2{ x: -1., y: 3. };
+        assert!(v.rotate_around(&pivot, PI).approx_eq(&(pivot * 2. - v), 1e-12));
+    }
+    #[test]
+    pub fn dist_to_plane(){
+        let mut n = Vector3{ x: 1., y: 1., z: 0. };
+        n.normalize();
+        let origin = Vector3{ x: 1., y: 0., z: 0. };
+        let above = Vector3{ x: 2., y: 1., z: 7. };
+        let below = Vector3{ x: 0., y: -1., z: -3. };
+        let on = Vector3{ x: 0., y: 1., z: 4. };
+        assert!((above.dist_to_plane(&origin, &n) - 2_f64.sqrt()).abs() < 1e-12);
+        assert!((below.dist_to_plane(&origin, &n) + 2_f64.sqrt()).abs() < 1e-12);
+        assert!(on.dist_to_plane(&origin, &n).abs() < 1e-12);
+        // flipping the normal flips the sign
+        assert!((above.dist_to_plane(&origin, &-n) + 2_f64.sqrt()).abs() < 1e-12);
+    }
+    #[test]
+    pub fn signed_angle_between(){
+        use std::f64::consts::PI;
+        let v1 = Vector2{ x: 2., y: 1. };
+        for angle in [0.3, 1.2, 0.5*PI, 2.5, -0.7, -2.9] {
+            let v2 = v1.rotate(angle) * 1.5;
+            let signed = Vector2::signed_angle_between(&v1, &v2);
+            assert!((signed - angle).abs() < 1e-12);
+            assert!((Vector2::signed_angle_between(&v2, &v1) + angle).abs() < 1e-12);
+            assert!((Vector2::angle_between(&v1, &v2) - angle.abs()).abs() < 1e-12);
+        }
+        // a quarter turn anti-clockwise used to give 1.5 PI
+        let v1 = Vector2{ x: 1., y: 0. };
+        assert_eq!(Vector2::angle_between(&v1, &Vector2{ x: 0., y: 1. }), 0.5*PI);
+        assert_eq!(Vector2::angle_between(&v1, &Vector2{ x: 0., y: -1. }), 0.5*PI);
+        assert_eq!(Vector2::signed_angle_between(&v1, &-v1), PI);
+        assert_eq!(Vector2::signed_angle_between(&-v1, &v1), PI);
+    }
+    #[test]
+    pub fn orthonormal_basis(){
+        let dirs = [
+            Vector3f64{ x: 0., y: 0., z: 1. },
+            Vector3{ x: -3., y: 0.001, z: 0. },
+            Vector3{ x: 1., y: 2., z: 3. },
+            Vector3{ x: -0.2, y: -5., z: 0.7 },
+            Vector3{ x: 1., y: 1., z: 1. }
+        ];
+        for d in dirs {
+            let (t, b, n) = d.orthonormal_basis();
+            for v in [t, b, n] { assert!((v.magn() - 1.).abs() < 1e-12); }
+            assert!(Vector3::scalar(&t, &b).abs() < 1e-12);
+            assert!(Vector3::scalar(&t, &n).abs() < 1e-12);
+            assert!(Vector3::scalar(&b, &n).abs() < 1e-12);
+            assert!(Vector3::crossp(&t, &b).approx_eq(&n, 1e-12));
+            assert!((n * d.magn()).approx_eq(&d, 1e-12));
+        }
+    }
+    #[test]
+    pub fn sort_lexicographically(){
+        let mut v = vec![
+            Vector2{ x: 1., y: 0. },
+            Vector2{ x: -2., y: 3. },
+            Vector2{ x: 1., y: -1. },
+            Vector2{ x: -2., y: -3. },
+            Vector2{ x: 0.5, y: 9. }
+        ];
+        v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(v, vec![
+            Vector2{ x: -2., y: -3. },
+            Vector2{ x: -2., y: 3. },
+            Vector2{ x: 0.5, y: 9. },
+            Vector2{ x: 1., y: -1. },
+            Vector2{ x: 1., y: 0. }
+        ]);
+        let mut w = v.clone();
+        w.reverse();
+        w.sort_by(|a, b| a.total_cmp(b));
+        assert_eq!(w, v);
+        assert_eq!(Vector2{ x: f64::NAN, y: 0. }.partial_cmp(&v[0]), None);
+    }
+    #[test]
+    pub fn convex_hull_square(){
+        let mut points = vec![
+            Vector2{ x: 2., y: 2. },
+            Vector2{ x: 0., y: 2. },
+            Vector2{ x: 1., y: 1. },
+            Vector2{ x: 0., y: 0. },
+            Vector2{ x: 0.5, y: 1.5 },
+            Vector2{ x: 2., y: 0. },
+            Vector2{ x: 1., y: 0. },
+            Vector2{ x: 0., y: 0. }
+        ];
+        for i in 0..10 {
+            points.push(Vector2{ x: 1., y: 1. }.jitter(i, 0.9));
+        }
+        assert_eq!(convex_hull(&points), vec![
+            Vector2{ x: 0., y: 0. },
+            Vector2{ x: 2., y: 0. },
+            Vector2{ x: 2., y: 2. },
+            Vector2{ x: 0., y: 2. }
+        ]);
+    }
+    #[test]
+    pub fn convex_hull_degenerate(){
+        let collinear = [
+            Vector2{ x: 1., y: 1. },
+            Vector2{ x: 3., y: 3. },
+            Vector2{ x: 0., y: 0. },
+            Vector2{ x: 2., y: 2. }
+        ];
+        assert_eq!(convex_hull(&collinear), vec![Vector2{ x: 0., y: 0. }, Vector2{ x: 3., y: 3. }]);
+        assert_eq!(convex_hull(&[]), vec![]);
+        let single = [Vector2{ x: 1., y: 2. }, Vector2{ x: 1., y: 2. }];
+        assert_eq!(convex_hull(&single), vec![single[0]]);
+    }
+    #[test]
+    pub fn point_in_concave_polygon(){
+        // a "U" shape, open at the top between x = 1 and x = 2
+        let u = [
+            Vector2{ x: 0., y: 0. },
+            Vector2{ x: 3., y: 0. },
+            Vector2{ x: 3., y: 3. },
+            Vector2{ x: 2., y: 3. },
+            Vector2{ x: 2., y: 1. },
+            Vector2{ x: 1., y: 1. },
+            Vector2{ x: 1., y: 3. },
+            Vector2{ x: 0., y: 3. }
+        ];
+        assert!(point_in_polygon(&Vector2{ x: 0.5, y: 2.5 }, &u));
+        assert!(point_in_polygon(&Vector2{ x: 2.5, y: 2.5 }, &u));
+        assert!(point_in_polygon(&Vector2{ x: 1.5, y: 0.5 }, &u));
+        // inside the concavity
+        assert!(!point_in_polygon(&Vector2{ x: 1.5, y: 2. }, &u));
+        // at the height of a vertex
+        assert!(!point_in_polygon(&Vector2{ x: 1.5, y: 3. }, &u));
+        assert!(!point_in_polygon(&Vector2{ x: -1., y: 1. }, &u));
+        assert!(!point_in_polygon(&Vector2{ x: 4., y: 1. }, &u));
+        assert!(!point_in_polygon(&Vector2{ x: 1., y: 1. }, &[]));
+    }
+    #[test]
+    pub fn polygon_area_and_centroid(){
+        let mut square = vec![
+            Vector2{ x: 0., y: 0. },
+            Vector2{ x: 1., y: 0. },
+            Vector2{ x: 1., y: 1. },
+            Vector2{ x: 0., y: 1. }
+        ];
+        assert_eq!(polygon_signed_area(&square), 1.);
+        assert_eq!(polygon_centroid(&square), Some(Vector2{ x: 0.5, y: 0.5 }));
+        square.reverse();
+        assert_eq!(polygon_signed_area(&square), -1.);
+        assert_eq!(polygon_centroid(&square), Some(Vector2{ x: 0.5, y: 0.5 }));
+
+        // an L shape made of three unit squares, with extra vertices along one edge
+        let l = [
+            Vector2{ x: 0., y: 0. },
+            Vector2{ x: 1., y: 0. },
+            Vector2{ x: 2., y: 0. },
+            Vector2{ x: 2., y: 1. },
+            Vector2{ x: 1., y: 1. },
+            Vector2{ x: 1., y: 2. },
+            Vector2{ x: 0., y: 2. }
+        ];
+        assert_eq!(polygon_signed_area(&l), 3.);
+        assert!(polygon_centroid(&l).unwrap().approx_eq(&Vector2{ x: 5./6., y: 5./6. }, 1e-12));
+
+        let collinear = [Vector2{ x: 0., y: 0. }, Vector2{ x: 1., y: 1. }, Vector2{ x: 2., y: 2. }];
+        assert_eq!(polygon_signed_area(&collinear), 0.);
+        assert_eq!(polygon_centroid(&collinear), None);
+        assert_eq!(polygon_centroid(&[]), None);
+    }
+    #[test]
+    pub fn segment_crossings(){
+        let (a1, a2) = (Vector2{ x: 0., y: 0. }, Vector2{ x: 4., y: 2. });
+        // crossing
+        let hit = segment_intersection(&a1, &a2, &Vector2{ x: 1., y: 3. }, &Vector2{ x: 3., y: -1. });
+        assert!(hit.unwrap().approx_eq(&Vector2{ x: 2., y: 1. }, 1e-12));
+        // would cross if the second segment was longer
+        assert_eq!(segment_intersection(&a1, &a2, &Vector2{ x: 1., y: 3. }, &Vector2{ x: 1.5, y: 2. }), None);
+        // parallel
+        assert_eq!(segment_intersection(&a1, &a2, &Vector2{ x: 0., y: 1. }, &Vector2{ x: 4., y: 3. }), None);
+        // collinear and overlapping
+        assert_eq!(segment_intersection(&a1, &a2, &Vector2{ x: 2., y: 1. }, &Vector2{ x: 6., y: 3. }), None);
+        // touching at an end point
+        let b2 = Vector2{ x: 5., y: 0. };
+        assert_eq!(segment_intersection(&a1, &a2, &a2, &b2), Some(a2));
+        assert_eq!(segment_intersection(&a1, &a2, &Vector2{ x: 2., y: 1. }, &b2), Some(Vector2{ x: 2., y: 1. }));
+    }
+    #[test]
+    pub fn cross_magnitude(){
+        let pairs = [
+            (Vector3{ x: 1., y: 2., z: 3. }, Vector3{ x: -4., y: 0.5, z: 2. }),
+            (Vector3{ x: 0.1, y: -7., z: 0. }, Vector3{ x: 3., y: 3., z: 3. }),
+            (Vector3{ x: 1., y: 1., z: 1. }, Vector3{ x: -2., y: -2., z: -2. })
+        ];
+        for (v1, v2) in pairs {
+            let expected = Vector3f64::crossp(&v1, &v2).magn();
+            assert!((Vector3::cross_magnitude(&v1, &v2) - expected).abs() <= 1e-12 * expected.max(1.));
+        }
+    }
+    #[test]
+    pub fn matrix4_axis_angle_columns(){
+        use std::f64::consts::PI;
+        let m = Matrix4::from_axis_angle(Vector3::UNIT_Z, 0.5*PI);
+        let column = |j: usize| Vector3{ x: m.m[0][j], y: m.m[1][j], z: m.m[2][j] };
+        // the columns are the images of the basis vectors: x -> y, y -> -x, z stays
+        assert!(column(0).approx_eq(&Vector3::UNIT_Y, 1e-12));
+        assert!(column(1).approx_eq(&-Vector3::UNIT_X, 1e-12));
+        assert!(column(2).approx_eq(&Vector3::UNIT_Z, 1e-12));
+        assert_eq!(m.m[3], [0., 0., 0., 1.]);
+        assert_eq!([m.m[0][3], m.m[1][3], m.m[2][3]], [0., 0., 0.]);
+
+        // for any axis, the columns match rotating each basis vector
+        let mut axis = Vector3{ x: 2., y: -1., z: 0.5 };
+        axis.normalize();
+        let m = Matrix4::from_axis_angle(axis, 2.2);
+        let column = |j: usize| Vector3{ x: m.m[0][j], y: m.m[1][j], z: m.m[2][j] };
+        for (j, basis) in [Vector3::UNIT_X, Vector3::UNIT_Y, Vector3::UNIT_Z].iter().enumerate() {
+            assert!(column(j).approx_eq(&basis.rotate(2.2, &axis), 1e-12));
+        }
+    }
+    #[test]
+    pub fn catmull_rom_hits_control_points(){
+        let p = [
+            Vector2{ x: -1.3, y: 0.7 },
+            Vector2{ x: 0.1, y: 2.9 },
+            Vector2{ x: 4.4, y: -0.3 },
+            Vector2{ x: 5., y: 1.7 }
+        ];
+        assert_eq!(Vector2::catmull_rom(&p[0], &p[1], &p[2], &p[3], 0.), p[1]);
+        assert_eq!(Vector2::catmull_rom(&p[0], &p[1], &p[2], &p[3], 1.), p[2]);
+        let q = [
+            Vector3{ x: -1.3, y: 0.7, z: 0.2 },
+            Vector3{ x: 0.1, y: 2.9, z: -8. },
+            Vector3{ x: 4.4, y: -0.3, z: 1.1 },
+            Vector3{ x: 5., y: 1.7, z: 0.6 }
+        ];
+        assert_eq!(Vector3::catmull_rom(&q[0], &q[1], &q[2], &q[3], 0.), q[1]);
+        assert_eq!(Vector3::catmull_rom(&q[0], &q[1], &q[2], &q[3], 1.), q[2]);
+        // evenly spaced collinear points are interpolated linearly
+        let line = [0., 1., 2., 3.].map(|k| Vector3{ x: k, y: 2. * k, z: -k });
+        let mid = Vector3::catmull_rom(&line[0], &line[1], &line[2], &line[3], 0.25);
+        assert!(mid.approx_eq(&Vector3{ x: 1.25, y: 2.5, z: -1.25 }, 1e-12));
+    }
+    #[test]
+    pub fn bezier_symmetric_midpoint(){
+        // the curves are symmetric about x = 1, so their midpoints lie on that line
+        let (p0, p1, p2) = (Vector2{ x: -1., y: 0.3 }, Vector2{ x: 1., y: 5. }, Vector2{ x: 3., y: 0.3 });
+        let mid = Vector2::bezier_quadratic(&p0, &p1, &p2, 0.5);
+        assert!(mid.approx_eq(&Vector2{ x: 1., y: 0.25 * 0.3 + 0.5 * 5. + 0.25 * 0.3 }, 1e-12));
+        assert_eq!(Vector2::bezier_quadratic(&p0, &p1, &p2, 0.), p0);
+        assert_eq!(Vector2::bezier_quadratic(&p0, &p1, &p2, 1.), p2);
+
+        let (q0, q1, q2, q3) = (
+            Vector3{ x: -1., y: 0., z: 2. },
+            Vector3{ x: 0., y: 3., z: 2. },
+            Vector3{ x: 2., y: 3., z: 2. },
+            Vector3{ x: 3., y: 0., z: 2. }
+        );
+        let mid = Vector3::bezier_cubic(&q0, &q1, &q2, &q3, 0.5);
+        assert!(mid.approx_eq(&Vector3{ x: 1., y: 2.25, z: 2. }, 1e-12));
+        assert_eq!(Vector3::bezier_cubic(&q0, &q1, &q2, &q3, 0.), q0);
+        assert_eq!(Vector3::bezier_cubic(&q0, &q1, &q2, &q3, 1.), q3);
+    }
+    #[test]
+    pub fn smooth_damp_converges_without_overshoot(){
+        let target = Vector2{ x: 3., y: -4. };
+        let mut pos = Vector2{ x: 0., y: 0. };
+        let mut velocity = Vector2{ x: 0., y: 0. };
+        let mut last_dist = Vector2::dist(&pos, &target);
+        for _ in 0..600 {
+            pos = Vector2::smooth_damp(&pos, &target, &mut velocity, 0.5, 1. / 60.);
+            let dist = Vector2::dist(&pos, &target);
+            assert!(dist <= last_dist);
+            // never crosses to the other side of the target
+            assert!(Vector2::scalar(&(target - pos), &target) >= 0.);
+            last_dist = dist;
+        }
+        assert!(last_dist < 1e-6);
+        // a zero smooth time and a large step still moves straight onto the target
+        let mut velocity = Vector3{ x: 0., y: 0., z: 0. };
+        let end = Vector3{ x: 1., y: 2., z: 3. };
+        let pos = Vector3::smooth_damp(&Vector3{ x: 0., y: 0., z: 0. }, &end, &mut velocity, 0., 1.);
+        assert!(pos.is_finite() && velocity.is_finite());
+        assert!(Vector3::dist(&pos, &end) < 1e-3);
+    }
+    #[test]
+    pub fn rotate_slice_matches_rotate(){
+        let polygon = [
+            Vector2{ x: 0., y: 0. },
+            Vector2{ x: 3., y: 0.5 },
+            Vector2{ x: 2., y: 2. },
+            Vector2{ x: -0.5, y: 1.5 }
+        ];
+        for angle in [0.3, 2., -4.1] {
+            let mut rotated = polygon;
+            rotate_slice(&mut rotated, angle);
+            for (r, p) in rotated.iter().zip(&polygon) {
+                assert_eq!(*r, p.rotate(angle));
+            }
+        }
+        rotate_slice(&mut [], 1.);
+    }
+    #[test]
+    pub fn lerp_towards_matches_lerp(){
+        let (a, b) = (Vector2{ x: 0.3, y: -1.7 }, Vector2{ x: 2.9, y: 0.1 });
+        for t in [0., 0.3, 1., 1.5] {
+            let mut v = a;
+            v.lerp_towards(&b, t);
+            assert_eq!(v, Vector2::lerp(&a, &b, t));
+        }
+        let (a, b) = (Vector3{ x: 0.3, y: -1.7, z: 5.5 }, Vector3{ x: 2.9, y: 0.1, z: -0.2 });
+        for t in [0., 0.3, 1., -0.5] {
+            let mut v = a;
+            v.lerp_towards(&b, t);
+            assert_eq!(v, Vector3::lerp(&a, &b, t));
+        }
+    }
+    #[test]
+    pub fn bounding_box_of_scattered_points(){
+        let points: Vec<Vector3> = (0..50).map(|i| Vector3{ x: 1., y: -2., z: 3. }.jitter(i, 4.)).collect();
+        let (min, max) = Vector3::bounding_box(&points).unwrap();
+        for p in &points {
+            assert!(p.x >= min.x && p.y >= min.y && p.z >= min.z);
+            assert!(p.x <= max.x && p.y <= max.y && p.z <= max.z);
+        }
+        // every side of the box touches a point
+        assert!(points.iter().any(|p| p.x == min.x) && points.iter().any(|p| p.x == max.x));
+        assert!(points.iter().any(|p| p.y == min.y) && points.iter().any(|p| p.y == max.y));
+        assert!(points.iter().any(|p| p.z == min.z) && points.iter().any(|p| p.z == max.z));
+
+        let flat: Vec<Vector2> = points.iter().map(|p| p.xy()).collect();
+        assert_eq!(Vector2::bounding_box(&flat), Some((min.xy(), max.xy())));
+        assert_eq!(Vector2::bounding_box(&flat[..1]), Some((flat[0], flat[0])));
+    }
+    #[test]
+    pub fn polyline_around_a_corner(){
+        let l = [Vector2{ x: 0., y: 3. }, Vector2{ x: 0., y: 0. }, Vector2{ x: 4., y: 0. }];
+        assert_eq!(polyline_point_at_distance(&l, 0.), Some(l[0]));
+        assert_eq!(polyline_point_at_distance(&l, 1.), Some(Vector2{ x: 0., y: 2. }));
+        assert_eq!(polyline_point_at_distance(&l, 3.), Some(l[1]));
+        assert_eq!(polyline_point_at_distance(&l, 4.5), Some(Vector2{ x: 1.5, y: 0. }));
+        assert_eq!(polyline_point_at_distance(&l, 7.), Some(l[2]));
+        assert_eq!(polyline_point_at_distance(&l, 7.5), None);
+        assert_eq!(polyline_point_at_distance(&l, -1.), None);
+        assert_eq!(polyline_point_at_distance(&l[..1], 0.), Some(l[0]));
+        assert_eq!(polyline_point_at_distance(&[], 0.), None);
+    }
+    #[test]
+    pub fn reflect_point_across_line(){
+        let x_axis = (Vector2{ x: -5., y: 0. }, Vector2{ x: 1., y: 0. });
+        let p = Vector2{ x: 2., y: 3. };
+        assert_eq!(p.reflect_point_across_line(&x_axis.0, &x_axis.1), Vector2{ x: 2., y: -3. });
+        // the diagonal y = x + 1, with a direction that is not normalized
+        let diagonal = (Vector2{ x: 0., y: 1. }, Vector2{ x: -3., y: -3. });
+        let mirrored = p.reflect_point_across_line(&diagonal.0, &diagonal.1);
+        assert!(mirrored.approx_eq(&Vector2{ x: 2., y: 3. }, 1e-12));
+        let q = Vector2{ x: 4., y: 0. };
+        let mirrored = q.reflect_point_across_line(&diagonal.0, &diagonal.1);
+        assert!(mirrored.approx_eq(&Vector2{ x: -1., y: 5. }, 1e-12));
+        // mirroring twice gives the point back
+        let back = mirrored.reflect_point_across_line(&diagonal.0, &diagonal.1);
+        assert!(back.approx_eq(&q, 1e-12));
+    }
+    #[test]
+    pub fn snap_to_cardinal(){
+        let at = |degrees: f64| Vector2{ x: 3., y: 0. }.rotate(degrees.to_radians());
+        let d = std::f64::consts::FRAC_1_SQRT_2;
+        let east = Vector2{ x: 1., y: 0. };
+        assert_eq!(at(22.).snap_to_cardinal(4), east);
+        assert_eq!(at(22.).snap_to_cardinal(8), east);
+        assert_eq!(at(30.).snap_to_cardinal(8), Vector2{ x: d, y: d });
+        assert_eq!(at(30.).snap_to_cardinal(4), east);
+        assert_eq!(at(140.).snap_to_cardinal(4), Vector2{ x: -1., y: 0. });
+        assert_eq!(at(-100.).snap_to_cardinal(4), Vector2{ x: 0., y: -1. });
+        assert_eq!(at(-30.).snap_to_cardinal(8), Vector2{ x: d, y: -d });
+        // close to the -x axis from below, where atan2 jumps to -PI
+        assert_eq!(at(185.).snap_to_cardinal(8), Vector2{ x: -1., y: 0. });
+        assert_eq!(Vector2{ x: 0., y: 0. }.snap_to_cardinal(8), Vector2{ x: 0., y: 0. });
+    }
+    #[test]
+    #[should_panic]
+    pub fn snap_to_cardinal_rejects_other_counts(){
+        Vector2{ x: 1., y: 1. }.snap_to_cardinal(6);
+    }
+    #[test]
+    pub fn magn_stable_near_overflow(){
+        let big = f64::MAX.sqrt();
+        let v = Vector2{ x: big, y: big };
+        assert_eq!(v.magn(), f64::INFINITY);
+        assert!((v.magn_stable() / (big * 2_f64.sqrt()) - 1.).abs() < 1e-15);
+        let v = Vector3{ x: big, y: -big, z: big };
+        assert_eq!(v.magn(), f64::INFINITY);
+        assert!((v.magn_stable() / (big * 3_f64.sqrt()) - 1.).abs() < 1e-15);
+        // tiny komponents underflow when squared
+        let v = Vector3f64{ x: 3e-200, y: 0., z: 4e-200 };
+        assert_eq!(v.magn(), 0.);
+        assert!((v.magn_stable() / 5e-200 - 1.).abs() < 1e-15);
+        // ordinary vectors agree with magn
+        let v = Vector3f64{ x: 1.5, y: -2., z: 0.25 };
+        assert!((v.magn_stable() - v.magn()).abs() < 1e-15);
+        assert_eq!(Vector3{ x: 0., y: 0., z: 0. }.magn_stable(), 0.);
+        assert_eq!(Vector3{ x: f64::INFINITY, y: 1., z: 0. }.magn_stable(), f64::INFINITY);
+        assert!(Vector3{ x: f64::NAN, y: 0., z: 0. }.magn_stable().is_nan());
+    }
+    #[test]
+    pub fn slice_conversions(){
+        let flat: Vec<f64> = vec![1., 2., 3., 4., 5., 6., 7.];
+        let points: Vec<Vector3> = flat.chunks(3).filter_map(|c| Vector3::try_from(c).ok()).collect();
+        assert_eq!(points, vec![Vector3{ x: 1., y: 2., z: 3. }, Vector3{ x: 4., y: 5., z: 6. }]);
+        assert!(Vector3::try_from(&flat[6..]).is_err());
+        assert!(Vector3::try_from(&flat[..4]).is_err());
+        let arr: &[f64; 3] = points[1].as_ref();
+        assert_eq!(arr, &[4., 5., 6.]);
+        assert_eq!(Vector2::try_from(&flat[5..]).ok(), Some(Vector2{ x: 6., y: 7. }));
+        assert_eq!(AsRef::<[f64; 2]>::as_ref(&Vector2{ x: 6., y: 7. }), &[6., 7.]);
+    }
+    #[test]
+    pub fn nlerp_stays_normalized(){
+        let mut a = Vector3{ x: 1., y: 2., z: -0.5 };
+        let mut b = Vector3{ x: -3., y: 0.2, z: 1. };
+        a.normalize();
+        b.normalize();
+        for i in 0..=10 {
+            let t = i as f64 / 10.;
+            assert!(Vector3::nlerp(&a, &b, t).is_normalized());
+        }
+        assert!(Vector3::nlerp(&a, &b, 0.).approx_eq(&a, 1e-12));
+        assert!(Vector3::nlerp(&a, &b, 1.).approx_eq(&b, 1e-12));
+        assert_eq!(Vector3::nlerp(&a, &-a, 0.5), Vector3{ x: 0., y: 0., z: 0. });
+    }
+    #[test]
+    pub fn winding_order(){
+        let ccw = vec![
+            Vector2{ x: -1., y: -1. },
+            Vector2{ x: 1., y: -1. },
+            Vector2{ x: 1., y: 1. },
+            Vector2{ x: -1., y: 1. }
+        ];
+        let cw: Vec<Vector2> = vec![ccw[0], ccw[3], ccw[2], ccw[1]];
+        assert!(polygon_is_ccw(&ccw));
+        assert!(!polygon_is_ccw(&cw));
+        let mut flipped = cw.clone();
+        reverse_winding(&mut flipped);
+        assert!(polygon_is_ccw(&flipped));
+        assert_eq!(polygon_signed_area(&flipped), polygon_signed_area(&ccw));
+        reverse_winding(&mut flipped);
+        assert_eq!(flipped, cw);
+    }
+    #[test]
+    pub fn triangulate_concave(){
+        let u = vec![
+            Vector2{ x: 0., y: 0. },
+            Vector2{ x: 3., y: 0. },
+            Vector2{ x: 3., y: 3. },
+            Vector2{ x: 2., y: 3. },
+            Vector2{ x: 2., y: 1. },
+            Vector2{ x: 1., y: 1. },
+            Vector2{ x: 1., y: 3. },
+            Vector2{ x: 0., y: 3. }
+        ];
+        let mut cw = u.clone();
+        reverse_winding(&mut cw);
+        for polygon in [u, cw] {
+            let triangles = triangulate(&polygon);
+            assert_eq!(triangles.len(), polygon.len() - 2);
+            let mut area = 0.;
+            for [a, b, c] in &triangles {
+                let t = [polygon[*a], polygon[*b], polygon[*c]];
+                let t_area = polygon_signed_area(&t);
+                assert!(t_area > 0.);
+                area += t_area;
+                // no triangle covers the notch of the U
+                assert!(!point_in_polygon(&Vector2{ x: 1.5, y: 2. }, &t));
+            }
+            assert!((area - polygon_signed_area(&polygon).abs()).abs() < 1e-12);
+        }
+        assert!(triangulate(&[Vector2{ x: 0., y: 0. }, Vector2{ x: 1., y: 0. }]).is_empty());
+    }
+    #[test]
+    pub fn degrees_and_radians(){
+        use std::f64::consts::PI;
+        let Radians(half_turn) = Degrees(180.).into();
+        assert!((half_turn - PI).abs() < 1e-15);
+        assert_eq!(Degrees::from(Radians(0.5 * PI)), Degrees(90.));
+        let Degrees(back) = Degrees::from(Radians::from(Degrees(-37.5)));
+        assert!((back + 37.5).abs() < 1e-12);
+
+        let v = Vector2{ x: 1., y: 0. };
+        assert!(v.rotate_deg(Degrees(90.)).approx_eq(&Vector2{ x: 0., y: 1. }, 1e-12));
+        assert!(v.rotate_deg(Degrees(-270.)).approx_eq(&Vector2{ x: 0., y: 1. }, 1e-12));
+        assert_eq!(v.rotate_deg(Degrees(33.)), v.rotate(33_f64.to_radians()));
+    }
+    #[test]
+    pub fn path_lerp_waypoints(){
+        let path = [Vector2{ x: 0., y: 0. }, Vector2{ x: 10., y: 0. }, Vector2{ x: 10., y: 1. }];
+        assert_eq!(Vector2::path_lerp(&path, 0.5), Some(path[1]));
+        assert_eq!(Vector2::path_lerp(&path, 0.), Some(path[0]));
+        assert_eq!(Vector2::path_lerp(&path, 1.), Some(path[2]));
+        assert_eq!(Vector2::path_lerp(&path, 0.1), Some(Vector2{ x: 2., y: 0. }));
+        assert_eq!(Vector2::path_lerp(&path, -3.), Some(path[0]));
+        assert_eq!(Vector2::path_lerp(&path, 7.), Some(path[2]));
+        assert_eq!(Vector2::path_lerp(&path[..1], 0.5), Some(path[0]));
+        assert_eq!(Vector2::path_lerp(&[], 0.5), None);
+    }
+    #[cfg(feature = "rand")]
+    #[test]
+    pub fn random_unit_mean(){
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(2024);
+        let n: usize = 20000;
+        let mean3: Vector3 = (0..n).map(|_| Vector3::random_unit(&mut rng)).sum::<Vector3>() / n as f64;
+        let mean2: Vector2 = (0..n).map(|_| Vector2::random_unit(&mut rng)).sum::<Vector2>() / n as f64;
+        // the standard error of each komponent is below 1/sqrt(n), so 0.03 is more than 4 of them
+        assert!(mean3.magn() < 0.03, "{:?}", mean3);
+        assert!(mean2.magn() < 0.03, "{:?}", mean2);
+    }
+    #[test]
+    pub fn reflect_if_incoming(){
+        let n = Vector2{ x: -1., y: 0. };
+        let incoming = Vector2{ x: 3., y: 0.5 };
+        let (bounced, hit) = incoming.reflect_if_incoming(&n);
+        assert!(hit);
+        assert_eq!(bounced, Vector2{ x: -3., y: 0.5 });
+        // once it moves away, a second bounce must not send it back
+        assert_eq!(bounced.reflect_if_incoming(&n), (bounced, false));
+        let sliding = Vector2{ x: 0., y: 2. };
+        assert_eq!(sliding.reflect_if_incoming(&n), (sliding, false));
+    }
+    #[test]
+    pub fn perspective_divide(){
+        let p = Vector3{ x: -2., y: 6., z: 4. };
+        assert_eq!(p.perspective_divide(), Vector2{ x: -0.5, y: 1.5 });
+        assert_eq!(p.try_perspective_divide(), Some(p.perspective_divide()));
+        // scaling a point along its ray from the eye doesn't move its projection
+        assert_eq!((p * 2.5).try_perspective_divide(), Some(Vector2{ x: -0.5, y: 1.5 }));
+        assert_eq!(Vector3{ x: 1., y: 1., z: 0. }.try_perspective_divide(), None);
+        assert_eq!(Vector3{ x: 1., y: 1., z: -1e-10 }.try_perspective_divide(), None);
+    }
+    // cargo test --release batch_timing -- --ignored --nocapture, with and without --features simd
+    #[test]
+    #[ignore]
+    pub fn batch_timing(){
+        use std::time::Instant;
+        for n in [10_000_usize, 1_000_000] {
+            let mut v: Vec<Vector3> = (0..n).map(|i| Vector3{ x: 1. + i as f64, y: 2., z: -0.5 }).collect();
+            let d: Vec<Vector3> = v.clone();
+            let runs: u32 = 50;
+            let start = Instant::now();
+            for _ in 0..runs { normalize_slice(std::hint::black_box(&mut v)); }
+            let norm = start.elapsed() / runs;
+            let start = Instant::now();
+            for _ in 0..runs { add_slice(std::hint::black_box(&mut v), &d); }
+            let add = start.elapsed() / runs;
+            println!("{} vectors: normalize_slice {:?}, add_slice {:?}", n, norm, add);
+        }
+    }
+    #[test]
+    pub fn f32_vectors(){
+        assert_eq!(std::mem::size_of::<Vector2f32>(), 8);
+        assert_eq!(std::mem::size_of::<Vector3f32>(), 12);
+        let mut v: Vector3f32 = Vector3{ x: 1., y: 2., z: 2. };
+        assert_eq!(v.magn(), 3.);
+        v.normalize();
+        assert!(v.is_normalized());
+        assert_eq!(2. * Vector2f32::UNIT_X + Vector2::UNIT_Y, Vector2{ x: 2_f32, y: 1. });
+        assert_eq!(Vector3::crossp(&Vector3f32::UNIT_X, &Vector3::UNIT_Y), Vector3f32::UNIT_Z);
+        // komponents that fit into an f32 survive the round trip
+        let w: Vector2 = Vector2{ x: 0.75, y: -3. };
+        assert_eq!(w.cast::<f32>().cast::<f64>(), w);
+        assert_eq!(Vector2f64::ONE, Vector2::ONE);
+    }
 }
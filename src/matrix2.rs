@@ -0,0 +1,105 @@
+//! 2x2 matrices for linear transforms in 2 dimentional space.
+use std::ops::Mul;
+use crate::vector2::Vector2;
+
+///2x2 Matrix, stored row by row
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix2 {
+    pub m: [[f64; 2]; 2]
+}
+
+impl Matrix2 {
+
+    #[inline(always)]
+    pub fn new(m: [[f64; 2]; 2]) -> Self {
+        Self { m }
+    }
+
+    /// The identity [`Matrix2`], which leaves every [`Vector2`] unchanged.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix2::*;
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 1.5, y: -2. };
+    /// assert_eq!(&Matrix2::identity() * &v, v);
+    /// ```
+    pub fn identity() -> Self {
+        Self { m: [[1., 0.],
+                   [0., 1.]] }
+    }
+
+    /// A [`Matrix2`] rotating (anti-clockwise) by an angle.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix2::*;
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 2., y: 1. };
+    /// let r = &Matrix2::rotation(1.25) * &v;
+    /// assert!((r.x - v.rotate(1.25).x).abs() < 1e-12);
+    /// assert!((r.y - v.rotate(1.25).y).abs() < 1e-12);
+    /// ```
+    pub fn rotation(angle: f64) -> Self {
+        let sin_a: f64 = angle.sin();
+        let cos_a: f64 = angle.cos();
+        Self { m: [[cos_a, -sin_a],
+                   [sin_a,  cos_a]] }
+    }
+
+    /// A [`Matrix2`] scaling each axis by the matching komponent of a [`Vector2`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix2::*;
+    /// use cute_gorl::vector2::*;
+    /// let s = Matrix2::scale(&Vector2 { x: 2., y: 0.5 });
+    /// assert_eq!(&s * &Vector2 { x: 1., y: 4. }, Vector2 { x: 2., y: 2. });
+    /// ```
+    pub fn scale(factors: &Vector2) -> Self {
+        Self { m: [[factors.x, 0.],
+                   [0., factors.y]] }
+    }
+
+    /// Calculates the determinant of a [`Matrix2`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix2::*;
+    /// let m = Matrix2::new([[3., 1.], [2., 4.]]);
+    /// assert_eq!(m.determinant(), 10.);
+    /// ```
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        self.m[0][0]*self.m[1][1] - self.m[0][1]*self.m[1][0]
+    }
+
+    /// Calculates the inverse of a [`Matrix2`], or `None` if the matrix is singular (its determinant is `0.0`).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix2::*;
+    /// let m = Matrix2::new([[2., 0.], [0., 4.]]);
+    /// assert_eq!(m.inverse(), Some(Matrix2::new([[0.5, 0.], [0., 0.25]])));
+    /// assert_eq!(Matrix2::new([[1., 2.], [2., 4.]]).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let det: f64 = self.determinant();
+        if det == 0.0 { return None; }
+        let f: f64 = 1. / det;
+        Some(Self { m: [[ self.m[1][1]*f, -self.m[0][1]*f],
+                        [-self.m[1][0]*f,  self.m[0][0]*f]] })
+    }
+}
+
+impl Mul<&Vector2> for &Matrix2 {
+    type Output = Vector2;
+    fn mul(self, v: &Vector2) -> Self::Output {
+        Vector2 { x: self.m[0][0]*v.x + self.m[0][1]*v.y,
+                  y: self.m[1][0]*v.x + self.m[1][1]*v.y }
+    }
+}
+impl Mul for &Matrix2 {
+    type Output = Matrix2;
+    fn mul(self, other: &Matrix2) -> Self::Output {
+        let a = &self.m;
+        let b = &other.m;
+        Matrix2 { m: [[a[0][0]*b[0][0] + a[0][1]*b[1][0], a[0][0]*b[0][1] + a[0][1]*b[1][1]],
+                      [a[1][0]*b[0][0] + a[1][1]*b[1][0], a[1][0]*b[0][1] + a[1][1]*b[1][1]]] }
+    }
+}
@@ -0,0 +1,115 @@
+//! 3x3 matrices for affine transforms in 2 dimentional space.
+//! Points are treated as column vectors `(x, y, 1)`, so in `&a * &b` the transform `b` is applied first.
+use std::ops::Mul;
+use crate::vector2::Vector2;
+
+///3x3 Matrix, stored row by row
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix3 {
+    pub m: [[f64; 3]; 3]
+}
+
+impl Matrix3 {
+
+    #[inline(always)]
+    pub fn new(m: [[f64; 3]; 3]) -> Self {
+        Self { m }
+    }
+
+    /// The identity [`Matrix3`], which leaves every point unchanged.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix3::*;
+    /// use cute_gorl::vector2::*;
+    /// let p = Vector2 { x: 1.5, y: -2. };
+    /// assert_eq!(Matrix3::identity().transform_point(&p), p);
+    /// ```
+    pub fn identity() -> Self {
+        Self { m: [[1., 0., 0.],
+                   [0., 1., 0.],
+                   [0., 0., 1.]] }
+    }
+
+    /// A [`Matrix3`] moving points by a [`Vector2`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix3::*;
+    /// use cute_gorl::vector2::*;
+    /// let t = Matrix3::from_translation(Vector2 { x: 2., y: -1. });
+    /// assert_eq!(t.transform_point(&Vector2 { x: 1., y: 1. }), Vector2 { x: 3., y: 0. });
+    /// ```
+    pub fn from_translation(offset: Vector2) -> Self {
+        Self { m: [[1., 0., offset.x],
+                   [0., 1., offset.y],
+                   [0., 0., 1.]] }
+    }
+
+    /// A [`Matrix3`] rotating (anti-clockwise) around the origin by an angle.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix3::*;
+    /// use cute_gorl::vector2::*;
+    /// use std::f64::consts::PI;
+    /// let p = Matrix3::from_rotation(0.5*PI).transform_point(&Vector2 { x: 1., y: 0. });
+    /// assert!(p.approx_eq(&Vector2 { x: 0., y: 1. }, 1e-12));
+    /// ```
+    pub fn from_rotation(angle: f64) -> Self {
+        let sin_a: f64 = angle.sin();
+        let cos_a: f64 = angle.cos();
+        Self { m: [[cos_a, -sin_a, 0.],
+                   [sin_a,  cos_a, 0.],
+                   [0., 0., 1.]] }
+    }
+
+    /// A [`Matrix3`] scaling each axis by the matching component of a [`Vector2`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix3::*;
+    /// use cute_gorl::vector2::*;
+    /// let s = Matrix3::from_scale(Vector2 { x: 2., y: 0.5 });
+    /// assert_eq!(s.transform_point(&Vector2 { x: 1., y: 4. }), Vector2 { x: 2., y: 2. });
+    /// ```
+    pub fn from_scale(factors: Vector2) -> Self {
+        Self { m: [[factors.x, 0., 0.],
+                   [0., factors.y, 0.],
+                   [0., 0., 1.]] }
+    }
+
+    /// Applies a [`Matrix3`] to a [`Vector2`] interpreted as a point, including the translation.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix3::*;
+    /// use cute_gorl::vector2::*;
+    /// let m = &Matrix3::from_translation(Vector2 { x: 1., y: -2. }) * &Matrix3::from_scale(Vector2 { x: 3., y: 0.5 });
+    /// assert_eq!(m.transform_point(&Vector2 { x: 2., y: 4. }), Vector2 { x: 7., y: 0. });
+    /// ```
+    pub fn transform_point(&self, p: &Vector2) -> Vector2 {
+        Vector2 { x: self.m[0][0]*p.x + self.m[0][1]*p.y + self.m[0][2],
+                  y: self.m[1][0]*p.x + self.m[1][1]*p.y + self.m[1][2] }
+    }
+
+    /// Applies a [`Matrix3`] to a [`Vector2`] interpreted as a direction, ignoring the translation.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix3::*;
+    /// use cute_gorl::vector2::*;
+    /// let t = Matrix3::from_translation(Vector2 { x: 2., y: -1. });
+    /// let v = Vector2 { x: 1., y: 1. };
+    /// assert_eq!(t.transform_vector(&v), v);
+    /// ```
+    pub fn transform_vector(&self, v: &Vector2) -> Vector2 {
+        Vector2 { x: self.m[0][0]*v.x + self.m[0][1]*v.y,
+                  y: self.m[1][0]*v.x + self.m[1][1]*v.y }
+    }
+}
+
+impl Mul for &Matrix3 {
+    type Output = Matrix3;
+    fn mul(self, other: &Matrix3) -> Self::Output {
+        let (a, b) = (&self.m, &other.m);
+        let e = |i: usize, j: usize| a[i][0]*b[0][j] + a[i][1]*b[1][j] + a[i][2]*b[2][j];
+        Matrix3 { m: [[e(0, 0), e(0, 1), e(0, 2)],
+                      [e(1, 0), e(1, 1), e(1, 2)],
+                      [e(2, 0), e(2, 1), e(2, 2)]] }
+    }
+}
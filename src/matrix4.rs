@@ -0,0 +1,195 @@
+//! 4x4 matrices for transforms in 3 dimentional space.
+//! Points are treated as column vectors `(x, y, z, 1)`, so in `&a * &b` the transform `b` is applied first.
+//! Like OpenGL, the coordinate system is right-handed and cameras look down the negative z-axis.
+use std::ops::Mul;
+use crate::vector3::Vector3;
+
+///4x4 Matrix, stored row by row
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix4 {
+    pub m: [[f64; 4]; 4]
+}
+
+impl Matrix4 {
+
+    #[inline(always)]
+    pub fn new(m: [[f64; 4]; 4]) -> Self {
+        Self { m }
+    }
+
+    /// The identity [`Matrix4`], which leaves every point unchanged.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix4::*;
+    /// use cute_gorl::vector3::*;
+    /// let p = Vector3 { x: 1.5, y: -2., z: 3. };
+    /// assert_eq!(Matrix4::identity().transform_point(&p), p);
+    /// ```
+    pub fn identity() -> Self {
+        Self { m: [[1., 0., 0., 0.],
+                   [0., 1., 0., 0.],
+                   [0., 0., 1., 0.],
+                   [0., 0., 0., 1.]] }
+    }
+
+    /// A [`Matrix4`] moving points by a [`Vector3`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix4::*;
+    /// use cute_gorl::vector3::*;
+    /// let t = Matrix4::from_translation(Vector3 { x: 2., y: -1., z: 0.5 });
+    /// assert_eq!(t.transform_point(&Vector3 { x: 1., y: 1., z: 1. }), Vector3 { x: 3., y: 0., z: 1.5 });
+    /// ```
+    pub fn from_translation(offset: Vector3) -> Self {
+        Self { m: [[1., 0., 0., offset.x],
+                   [0., 1., 0., offset.y],
+                   [0., 0., 1., offset.z],
+                   [0., 0., 0., 1.]] }
+    }
+
+    /// A [`Matrix4`] scaling each axis by the matching component of a [`Vector3`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix4::*;
+    /// use cute_gorl::vector3::*;
+    /// let s = Matrix4::from_scale(Vector3 { x: 2., y: 0.5, z: -1. });
+    /// assert_eq!(s.transform_point(&Vector3 { x: 1., y: 4., z: 3. }), Vector3 { x: 2., y: 2., z: -3. });
+    /// ```
+    pub fn from_scale(factors: Vector3) -> Self {
+        Self { m: [[factors.x, 0., 0., 0.],
+                   [0., factors.y, 0., 0.],
+                   [0., 0., factors.z, 0.],
+                   [0., 0., 0., 1.]] }
+    }
+
+    /// A [`Matrix4`] rotating by an angle around a normalized axis, following the right hand rule
+    /// just like [`Vector3::rotate`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix4::*;
+    /// use cute_gorl::vector3::*;
+    /// use std::f64::consts::PI;
+    /// let r = Matrix4::from_axis_angle(Vector3 { x: 0., y: 0., z: 1. }, 0.5*PI);
+    /// let p = r.transform_point(&Vector3 { x: 1., y: 0., z: 0. });
+    /// assert!(p.approx_eq(&Vector3 { x: 0., y: 1., z: 0. }, 1e-12));
+    /// ```
+    pub fn from_axis_angle(axis: Vector3, angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        let t: f64 = 1. - c;
+        let Vector3 { x, y, z } = axis;
+        Self { m: [[t*x*x + c,   t*x*y - s*z, t*x*z + s*y, 0.],
+                   [t*x*y + s*z, t*y*y + c,   t*y*z - s*x, 0.],
+                   [t*x*z - s*y, t*y*z + s*x, t*z*z + c,   0.],
+                   [0., 0., 0., 1.]] }
+    }
+
+    /// An OpenGL-style perspective projection [`Matrix4`], with the vertical field of view `fov_y` in radians,
+    /// the `aspect` ratio width / height, and the distances to the `near` and `far` clipping planes.
+    /// Points between the planes end up with a z in `[-1, 1]` after [`Matrix4::transform_point`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix4::*;
+    /// use cute_gorl::vector3::*;
+    /// let p = Matrix4::perspective(1., 1.5, 0.1, 100.);
+    /// let near = p.transform_point(&Vector3 { x: 0., y: 0., z: -0.1 });
+    /// let far = p.transform_point(&Vector3 { x: 0., y: 0., z: -100. });
+    /// assert!((near.z + 1.).abs() < 1e-12 && (far.z - 1.).abs() < 1e-12);
+    /// ```
+    pub fn perspective(fov_y: f64, aspect: f64, near: f64, far: f64) -> Self {
+        let f: f64 = 1. / (0.5 * fov_y).tan();
+        let inv_depth: f64 = 1. / (near - far);
+        Self { m: [[f / aspect, 0., 0., 0.],
+                   [0., f, 0., 0.],
+                   [0., 0., (far + near) * inv_depth, 2. * far * near * inv_depth],
+                   [0., 0., -1., 0.]] }
+    }
+
+    /// A view [`Matrix4`] for a camera at `eye` looking at `target`, which ends up on the negative z-axis,
+    /// with `up` pointing roughly upwards on screen. `up` must not be parallel to the viewing direction.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix4::*;
+    /// use cute_gorl::vector3::*;
+    /// let eye = Vector3 { x: 0., y: 0., z: 5. };
+    /// let view = Matrix4::look_at(&eye, &Vector3 { x: 0., y: 0., z: 0. }, &Vector3 { x: 0., y: 1., z: 0. });
+    /// assert!(view.transform_point(&eye).approx_eq(&Vector3 { x: 0., y: 0., z: 0. }, 1e-12));
+    /// let p = view.transform_point(&Vector3 { x: 1., y: 2., z: 0. });
+    /// assert!(p.approx_eq(&Vector3 { x: 1., y: 2., z: -5. }, 1e-12));
+    /// ```
+    pub fn look_at(eye: &Vector3, target: &Vector3, up: &Vector3) -> Self {
+        let mut f: Vector3 = target - eye;
+        f.normalize();
+        let mut s: Vector3 = Vector3::crossp(&f, up);
+        s.normalize();
+        let u: Vector3 = Vector3::crossp(&s, &f);
+        Self { m: [[ s.x,  s.y,  s.z, -Vector3::scalar(&s, eye)],
+                   [ u.x,  u.y,  u.z, -Vector3::scalar(&u, eye)],
+                   [-f.x, -f.y, -f.z,  Vector3::scalar(&f, eye)],
+                   [0., 0., 0., 1.]] }
+    }
+
+    /// Applies a [`Matrix4`] to a [`Vector3`] interpreted as a point, including the translation.
+    /// For projections the result is divided by the resulting w component.
+    /// If w is `0.0`, e.g. for a point in the plane of the eye under [`Matrix4::perspective`], the point is returned
+    /// undivided and can't be told apart from a valid result; use [`Matrix4::try_transform_point`] to catch that case.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix4::*;
+    /// use cute_gorl::vector3::*;
+    /// let m = &Matrix4::from_translation(Vector3 { x: 1., y: 0., z: -2. }) * &Matrix4::from_scale(Vector3 { x: 2., y: 2., z: 2. });
+    /// assert_eq!(m.transform_point(&Vector3 { x: 1., y: 1., z: 1. }), Vector3 { x: 3., y: 2., z: 0. });
+    /// ```
+    pub fn transform_point(&self, p: &Vector3) -> Vector3 {
+        let (res, w) = self.transform_homogeneous(p);
+        if w == 1. || w == 0. { res } else { res / w }
+    }
+
+    /// Checked version of [`Matrix4::transform_point`], which is `None` if the absolute value of the resulting w component
+    /// is below [`crate::EPSILON`], i.e. if a projection sends the point to infinity.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix4::*;
+    /// use cute_gorl::vector3::*;
+    /// let p = Matrix4::perspective(1., 1.5, 0.1, 100.);
+    /// assert!(p.try_transform_point(&Vector3 { x: 0., y: 0., z: -1. }).is_some());
+    /// assert_eq!(p.try_transform_point(&Vector3 { x: 1., y: 2., z: 0. }), None);
+    /// ```
+    pub fn try_transform_point(&self, p: &Vector3) -> Option<Vector3> {
+        let (res, w) = self.transform_homogeneous(p);
+        if w.abs() < crate::EPSILON { None } else if w == 1. { Some(res) } else { Some(res / w) }
+    }
+
+    // applies the matrix to (x, y, z, 1), returning xyz and w separately
+    fn transform_homogeneous(&self, p: &Vector3) -> (Vector3, f64) {
+        let m = &self.m;
+        let row = |i: usize| m[i][0]*p.x + m[i][1]*p.y + m[i][2]*p.z + m[i][3];
+        (Vector3 { x: row(0), y: row(1), z: row(2) }, row(3))
+    }
+
+    /// Applies a [`Matrix4`] to a [`Vector3`] interpreted as a direction, ignoring the translation.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::matrix4::*;
+    /// use cute_gorl::vector3::*;
+    /// let t = Matrix4::from_translation(Vector3 { x: 2., y: -1., z: 5. });
+    /// let v = Vector3 { x: 1., y: 1., z: 1. };
+    /// assert_eq!(t.transform_direction(&v), v);
+    /// ```
+    pub fn transform_direction(&self, v: &Vector3) -> Vector3 {
+        let m = &self.m;
+        let row = |i: usize| m[i][0]*v.x + m[i][1]*v.y + m[i][2]*v.z;
+        Vector3 { x: row(0), y: row(1), z: row(2) }
+    }
+}
+
+impl Mul for &Matrix4 {
+    type Output = Matrix4;
+    fn mul(self, other: &Matrix4) -> Self::Output {
+        let (a, b) = (&self.m, &other.m);
+        let e = |i: usize, j: usize| a[i][0]*b[0][j] + a[i][1]*b[1][j] + a[i][2]*b[2][j] + a[i][3]*b[3][j];
+        Matrix4 { m: [[e(0, 0), e(0, 1), e(0, 2), e(0, 3)],
+                      [e(1, 0), e(1, 1), e(1, 2), e(1, 3)],
+                      [e(2, 0), e(2, 1), e(2, 2), e(2, 3)],
+                      [e(3, 0), e(3, 1), e(3, 2), e(3, 3)]] }
+    }
+}
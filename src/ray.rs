@@ -0,0 +1,71 @@
+//! Rays in 3 dimentional space, for picking and raycasting.
+use crate::vector3::Vector3;
+
+///3D Ray, starting at `origin` and going along `dir`. `dir` is assumed to be normalized,
+///so that the `t` returned by the intersection tests is the distance travelled along the ray.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Ray3 {
+    pub origin: Vector3,
+    pub dir: Vector3
+}
+
+impl Ray3 {
+
+    #[inline(always)]
+    pub fn new(origin: Vector3, dir: Vector3) -> Self {
+        Self { origin, dir }
+    }
+
+    /// The point reached after travelling `t` along a [`Ray3`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::ray::*;
+    /// use cute_gorl::vector3::*;
+    /// let r = Ray3 { origin: Vector3 { x: 1., y: 0., z: 0. }, dir: Vector3 { x: 0., y: 1., z: 0. } };
+    /// assert_eq!(r.at(2.5), Vector3 { x: 1., y: 2.5, z: 0. });
+    /// ```
+    #[inline]
+    pub fn at(&self, t: f64) -> Vector3 {
+        &self.origin + &(&self.dir * t)
+    }
+
+    /// Intersects a [`Ray3`] with a sphere, returning the smallest positive `t` at which the ray hits its surface.
+    /// A ray starting inside of the sphere hits it on the way out; `None` if the sphere is missed or lies behind the ray.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::ray::*;
+    /// use cute_gorl::vector3::*;
+    /// let r = Ray3 { origin: Vector3 { x: 0., y: 0., z: -5. }, dir: Vector3 { x: 0., y: 0., z: 1. } };
+    /// assert_eq!(r.intersect_sphere(&NULL, 1.), Some(4.));
+    /// ```
+    pub fn intersect_sphere(&self, center: &Vector3, radius: f64) -> Option<f64> {
+        let oc: Vector3 = &self.origin - center;
+        let b: f64 = Vector3::scalar(&oc, &self.dir);
+        let c: f64 = oc.magn_sq() - radius*radius;
+        let disc: f64 = b*b - c;
+        if disc < 0.0 { return None; }
+        let sq: f64 = disc.sqrt();
+        let near: f64 = -b - sq;
+        if near > 0.0 { return Some(near); }
+        let far: f64 = -b + sq;
+        if far > 0.0 { Some(far) } else { None }
+    }
+
+    /// Intersects a [`Ray3`] with the plane through `point` with the given `normal`, returning the `t` at which it is hit.
+    /// `None` if the ray runs parallel to the plane or points away from it.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::ray::*;
+    /// use cute_gorl::vector3::*;
+    /// let r = Ray3 { origin: Vector3 { x: 3., y: 2., z: 0. }, dir: Vector3 { x: 0., y: -1., z: 0. } };
+    /// let n = Vector3 { x: 0., y: 1., z: 0. };
+    /// assert_eq!(r.intersect_plane(&NULL, &n), Some(2.));
+    /// assert_eq!(r.intersect_plane(&Vector3 { x: 0., y: 5., z: 0. }, &n), None);
+    /// ```
+    pub fn intersect_plane(&self, point: &Vector3, normal: &Vector3) -> Option<f64> {
+        let denom: f64 = Vector3::scalar(normal, &self.dir);
+        if denom == 0.0 { return None; }
+        let t: f64 = Vector3::scalar(&(point - &self.origin), normal) / denom;
+        if t >= 0.0 { Some(t) } else { None }
+    }
+}
@@ -0,0 +1,118 @@
+//! The floating-point types the vectors can be built from.
+//! [`Vector2`](crate::vector2::Vector2) and [`Vector3`](crate::vector3::Vector3) are generic over a [`Scalar`]
+//! and default to `f64`, so `f32` is only needed where memory matters, e.g. in a vertex buffer.
+//! # Examples
+//! ```
+//! use cute_gorl::vector2::*;
+//! let v: Vector2f32 = Vector2 { x: 3., y: 4. };
+//! assert_eq!(v.magn(), 5_f32);
+//! assert_eq!(std::mem::size_of::<Vector2f32>(), 8);
+//! ```
+use std::ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign, Neg};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A floating-point komponent type, implemented for `f32` and `f64` only.
+/// The methods mirror the ones of the same name on the primitive types.
+pub trait Scalar:
+    sealed::Sealed + Copy + Default + PartialEq + PartialOrd + fmt::Debug + fmt::Display
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+    + AddAssign + SubAssign + MulAssign + DivAssign + Sum
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+    const HALF: Self;
+    const PI: Self;
+    /// The default tolerance of comparisons like [`Vector2::is_normalized`](crate::vector2::Vector2::is_normalized).
+    /// [`EPSILON`](crate::EPSILON) for `f64`, and `1e-5` for `f32`, which can't resolve differences much below that near `1.0`.
+    const EPSILON: Self;
+    /// Converts from an `f64`, rounding to the nearest `f32` if need be.
+    fn from_f64(v: f64) -> Self;
+    /// Converts to an `f64`, which is exact for both types.
+    fn to_f64(self) -> f64;
+    fn abs(self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn recip(self) -> Self;
+    fn signum(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_scalar {
+    ($t:ident, $eps:expr) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0.;
+            const ONE: Self = 1.;
+            const TWO: Self = 2.;
+            const HALF: Self = 0.5;
+            const PI: Self = std::$t::consts::PI;
+            const EPSILON: Self = $eps;
+            #[inline(always)]
+            fn from_f64(v: f64) -> Self { v as $t }
+            #[inline(always)]
+            fn to_f64(self) -> f64 { self as f64 }
+            #[inline(always)]
+            fn abs(self) -> Self { $t::abs(self) }
+            #[inline(always)]
+            fn min(self, other: Self) -> Self { $t::min(self, other) }
+            #[inline(always)]
+            fn max(self, other: Self) -> Self { $t::max(self, other) }
+            #[inline(always)]
+            fn clamp(self, min: Self, max: Self) -> Self { $t::clamp(self, min, max) }
+            #[inline(always)]
+            fn sqrt(self) -> Self { $t::sqrt(self) }
+            #[inline(always)]
+            fn hypot(self, other: Self) -> Self { $t::hypot(self, other) }
+            #[inline(always)]
+            fn mul_add(self, a: Self, b: Self) -> Self { $t::mul_add(self, a, b) }
+            #[inline(always)]
+            fn floor(self) -> Self { $t::floor(self) }
+            #[inline(always)]
+            fn ceil(self) -> Self { $t::ceil(self) }
+            #[inline(always)]
+            fn round(self) -> Self { $t::round(self) }
+            #[inline(always)]
+            fn recip(self) -> Self { $t::recip(self) }
+            #[inline(always)]
+            fn signum(self) -> Self { $t::signum(self) }
+            #[inline(always)]
+            fn rem_euclid(self, rhs: Self) -> Self { $t::rem_euclid(self, rhs) }
+            #[inline(always)]
+            fn sin_cos(self) -> (Self, Self) { $t::sin_cos(self) }
+            #[inline(always)]
+            fn acos(self) -> Self { $t::acos(self) }
+            #[inline(always)]
+            fn atan2(self, other: Self) -> Self { $t::atan2(self, other) }
+            #[inline(always)]
+            fn is_nan(self) -> bool { $t::is_nan(self) }
+            #[inline(always)]
+            fn is_finite(self) -> bool { $t::is_finite(self) }
+            #[inline(always)]
+            fn total_cmp(&self, other: &Self) -> Ordering { $t::total_cmp(self, other) }
+        }
+    };
+}
+
+impl_scalar!(f32, 1e-5);
+impl_scalar!(f64, crate::EPSILON);
@@ -1,24 +1,100 @@
 //! Mathematical vectors in 2 dimentional space. 
 use std::ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign, Neg};
-use std::cmp::PartialEq;
+use std::cmp::{PartialEq, PartialOrd, Ordering};
+use std::fmt;
+use std::iter::Sum;
+use std::hash::{Hash, Hasher};
+use std::array::TryFromSliceError;
 use std::f64::consts::PI;
-use crate::{math};
+use crate::{math, scalar::Scalar, vector3::Vector3, angle::{Degrees, Radians}};
 
 ///2D Vector
-#[derive(Copy, Clone, Debug, Default)]
-pub struct Vector2 {
-    pub x: f64,
-    pub y: f64
+///
+///Generic over its komponent type, which is `f64` unless stated otherwise, see [`Scalar`].
+///Laid out like `[T; 2]`, i.e. the components are stored in order without padding.
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct Vector2<T: Scalar = f64> {
+    pub x: T,
+    pub y: T
 }
 
+/// A [`Vector2`] of `f64`s, the same as a plain `Vector2`.
+pub type Vector2f64 = Vector2<f64>;
+/// A [`Vector2`] of `f32`s, half the size of a [`Vector2f64`].
+pub type Vector2f32 = Vector2<f32>;
+
 /// The Nullvector (0 0)
 pub const NULL: Vector2 = Vector2 { x: 0., y: 0. };
 
-impl Vector2 {
+/// A [`Vector2`] that can be used as a key in a `HashMap` or `HashSet`.
+/// Components are compared by their bit patterns rather than by `==`, so `-0.0` and `0.0` are different keys
+/// while a `NaN` equals itself. This makes most sense for vectors with integer components, e.g. after [`Vector2::round`].
+/// # Examples
+/// ```
+/// use cute_gorl::vector2::*;
+/// use std::collections::HashSet;
+/// let mut set = HashSet::new();
+/// set.insert(HashableVector2(Vector2 { x: 1., y: 2. }));
+/// assert!(set.contains(&HashableVector2(Vector2 { x: 1., y: 2. })));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct HashableVector2(pub Vector2);
+
+impl HashableVector2 {
+    #[inline]
+    fn bits(&self) -> (u64, u64) {
+        (self.0.x.to_bits(), self.0.y.to_bits())
+    }
+}
+impl PartialEq for HashableVector2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+impl Eq for HashableVector2 {}
+impl Hash for HashableVector2 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+impl From<Vector2> for HashableVector2 {
+    #[inline]
+    fn from(v: Vector2) -> Self {
+        Self(v)
+    }
+}
+
+/// The angle between two [`Vector2`]s in several forms, as calculated by [`Vector2::angle_info`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct AngleInfo {
+    /// The unsigned angle in radians, in `[0, PI]`.
+    pub radians: f64,
+    /// The unsigned angle in degrees, in `[0, 180]`.
+    pub degrees: f64,
+    /// The signed angle in radians, in `[-PI, PI]`. Positive if the second vector lies anti-clockwise of the first.
+    pub signed: f64
+}
+
+impl<T: Scalar> Vector2<T> {
+    /// The null-vector (0 0), the same as [`NULL`].
+    pub const ZERO: Self = Self { x: T::ZERO, y: T::ZERO };
+    /// The vector (1 1).
+    pub const ONE: Self = Self { x: T::ONE, y: T::ONE };
+    /// The unit vector along the x-axis (1 0).
+    pub const UNIT_X: Self = Self { x: T::ONE, y: T::ZERO };
+    /// The unit vector along the y-axis (0 1).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let jump = Vector2::UNIT_Y * 3.;
+    /// assert_eq!(jump, Vector2 { x: 0., y: 3. });
+    /// ```
+    pub const UNIT_Y: Self = Self { x: T::ZERO, y: T::ONE };
     
     #[inline(always)]
-    pub fn new(x: f64, y: f64) -> Self {
-        Self { x: x, y: y }
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
     }
 
     /// Tetermines whether or not a [`Vector2`]'s komponents are all equal to `0.0`.
@@ -32,7 +108,20 @@ impl Vector2 {
     /// ```
     #[inline]
     pub fn is_nullvector(&self) -> bool {
-        self.x == 0.0 && self.y == 0.0
+        self.is_nullvector_eps(T::ZERO)
+    }
+
+    /// Determines whether or not a [`Vector2`]'s components all lie within `eps` of `0.0`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v: Vector2 = Vector2 { x: 1e-6, y: -1e-6 };
+    /// assert!(v.is_nullvector_eps(1e-5));
+    /// assert!( !(v.is_nullvector_eps(cute_gorl::EPSILON)) );
+    /// ```
+    #[inline]
+    pub fn is_nullvector_eps(&self, eps: T) -> bool {
+        self.x.abs() <= eps && self.y.abs() <= eps
     }
 
     /// Tetermines whether or not a [`Vector2`] is normalized (of length `1`).
@@ -45,11 +134,55 @@ impl Vector2 {
     /// assert!(v.is_normalized());
     /// ```
     pub fn is_normalized(&self) -> bool {
-        let diff = 1.0 - self.magn_sq();
-        diff.abs() < math::EPSILON
+        self.is_normalized_eps(T::EPSILON)
+    }
+
+    /// Determines whether or not a [`Vector2`] is normalized, using a caller-supplied tolerance `eps` instead of the default.
+    /// The difference is measured on the square of the magnitude, just like in [`Vector2::is_normalized`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v: Vector2 = Vector2 { x: 1.00001, y: 0. };
+    /// assert!(v.is_normalized_eps(1e-4));
+    /// assert!( !(v.is_normalized()) );
+    /// ```
+    pub fn is_normalized_eps(&self, eps: T) -> bool {
+        let diff = T::ONE - self.magn_sq();
+        diff.abs() < eps
+    }
+
+    /// Determines whether or not two [`Vector2`]s are equal, allowing each component to differ by up to `epsilon`.
+    /// Prefer this over `==` for vectors that went through floating-point arithmetic.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v1 = Vector2 { x: 1., y: 2. };
+    /// let v2 = Vector2 { x: 1. + 1e-10, y: 2. };
+    /// assert!(v1.approx_eq(&v2, 1e-9));
+    /// assert!( !(v1.approx_eq(&Vector2 { x: 1.001, y: 2. }, 1e-9)) );
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        (self.x - other.x).abs() <= epsilon &&
+        (self.y - other.y).abs() <= epsilon
+    }
+
+    /// Determines whether or not two [`Vector2`]s are equal within the same tolerance (`1e-8`) that [`Vector2::is_normalized`] uses.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v1 = Vector2 { x: 1., y: 2. };
+    /// let v2 = Vector2 { x: 1. + 1e-10, y: 2. };
+    /// assert_ne!(v1, v2);
+    /// assert!(v1.approx_eq_default(&v2));
+    /// ```
+    #[inline]
+    pub fn approx_eq_default(&self, other: &Self) -> bool {
+        self.approx_eq(other, T::EPSILON)
     }
 
     /// Tetermines whether or not one [`Vector2`] is a multiple of the other. The inputs must not be null-vectors.
+    /// The crossproduct has to be exactly zero, see [`Vector2::is_collinear_eps`] for a tolerant version.
     /// # Examples
     /// ```
     /// use cute_gorl::vector2::*;
@@ -61,7 +194,56 @@ impl Vector2 {
     /// ```
     #[inline]
     pub fn is_collinear(v1:&Self, v2:&Self) -> bool {
-        v1.x*v2.y - v1.y*v2.x == 0.0
+        v1.x*v2.y - v1.y*v2.x == T::ZERO
+    }
+
+    /// Tolerant version of [`Vector2::is_collinear`], which only holds exactly and so rarely for computed vectors.
+    /// The test is relative: the magnitude of the crossproduct may be at most `eps` times the product of the magnitudes,
+    /// i.e. the sine of the angle between the vectors may be at most `eps`. `1e-8` ([`crate::EPSILON`]) is a good default.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v1: Vector2 = Vector2 { x: 0.1, y: 0.7 };
+    /// let v2: Vector2 = &(&v1 / 3.) * 7.3;
+    /// assert!( !(Vector2::is_collinear(&v1, &v2)) );
+    /// assert!( Vector2::is_collinear_eps(&v1, &v2, cute_gorl::EPSILON) );
+    /// ```
+    pub fn is_collinear_eps(v1: &Self, v2: &Self, eps: T) -> bool {
+        let cross: T = v1.x*v2.y - v1.y*v2.x;
+        cross*cross <= eps*eps * v1.magn_sq() * v2.magn_sq()
+    }
+
+    /// Determines whether or not two [`Vector2`]s point along the same line, in the same or in opposite directions.
+    /// This is the same relative test as [`Vector2::is_collinear_eps`]: the sine of the angle between them may be at most `eps`.
+    /// Unlike there, the null-vector has no direction and so is never parallel to anything.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 3., y: 1. };
+    /// assert!( Vector2::is_parallel(&v, &Vector2 { x: -6., y: -2. }, 1e-8) );
+    /// assert!( !(Vector2::is_parallel(&v, &Vector2 { x: 3., y: 1.001 }, 1e-8)) );
+    /// assert!( !(Vector2::is_parallel(&v, &Vector2 { x: 0., y: 0. }, 1e-8)) );
+    /// ```
+    pub fn is_parallel(v1: &Self, v2: &Self, eps: T) -> bool {
+        !v1.is_nullvector() && !v2.is_nullvector() && Self::is_collinear_eps(v1, v2, eps)
+    }
+
+    /// Determines whether or not two [`Vector2`]s are at a right angle to each other.
+    /// The test is relative like in [`Vector2::is_collinear_eps`]: the cosine of the angle between them may be at most `eps`.
+    /// The null-vector has no direction and so is never perpendicular to anything.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 3., y: 1. };
+    /// assert!( Vector2::is_perpendicular(&v, &Vector2 { x: -2., y: 6. }, 1e-8) );
+    /// assert!( !(Vector2::is_perpendicular(&v, &Vector2 { x: -2., y: 6.001 }, 1e-8)) );
+    /// assert!( !(Vector2::is_perpendicular(&Vector2 { x: 0., y: 0. }, &v, 1e-8)) );
+    /// ```
+    pub fn is_perpendicular(v1: &Self, v2: &Self, eps: T) -> bool {
+        if v1.is_nullvector() || v2.is_nullvector() { return false; }
+        // compares the dotproduct to the product of the magnitudes
+        let dot: T = Self::scalar(v1, v2);
+        dot*dot <= eps*eps * v1.magn_sq() * v2.magn_sq()
     }
 
     /// Calculates the scalar/dot-product of two [`Vector2`]s.
@@ -74,10 +256,38 @@ impl Vector2 {
     /// assert_eq!( scalar, 3. );
     /// ```
     #[inline]
-    pub fn scalar(v1:&Self, v2:&Self) -> f64 {
+    pub fn scalar(v1:&Self, v2:&Self) -> T {
         v1.x * v2.x + v1.y * v2.y
     }
 
+    /// Method form of [`Vector2::scalar`], the scalar/dot-product of two [`Vector2`]s.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let a = Vector2 { x: 1.5, y: -2. };
+    /// let b = Vector2 { x: 2., y: 0.5 };
+    /// assert_eq!(a.dot(&b), 2.);
+    /// ```
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T {
+        Self::scalar(self, other)
+    }
+
+    /// Calculates the perp-dot product of two [`Vector2`]s: the z component of their 3D crossproduct.
+    /// It is positive if `v2` lies anti-clockwise of `v1`, negative if clockwise and `0.0` if they are collinear.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v1 = Vector2 { x: 2., y: 0. };
+    /// let v2 = Vector2 { x: 1., y: 3. };
+    /// assert_eq!(Vector2::perp_dot(&v1, &v2), 6.);
+    /// assert_eq!(Vector2::perp_dot(&v2, &v1), -6.);
+    /// ```
+    #[inline]
+    pub fn perp_dot(v1: &Self, v2: &Self) -> T {
+        v1.x * v2.y - v1.y * v2.x
+    }
+
     /// Scales a [`Vector2`] to a magnitude of 1.
     /// # Examples
     /// ```
@@ -88,12 +298,55 @@ impl Vector2 {
     /// v2.normalize();
     /// assert!(v1.is_normalized() && v2.is_normalized());
     /// ```
-    pub fn normalize(&mut self) -> () {
-        let inv_magn = 1.0 / self.magn();
+    pub fn normalize(&mut self) {
+        let inv_magn = T::ONE / self.magn();
         self.x *= inv_magn;
         self.y *= inv_magn;
     }
 
+    /// Returns a normalized copy of a [`Vector2`] together with its original magnitude, leaving it unchanged.
+    /// The null-vector has no direction and yields `(NULL, 0.0)`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 3., y: 4. };
+    /// let (n, len) = v.normalized_and_length();
+    /// assert!(n.is_normalized());
+    /// assert_eq!(len, 5.);
+    /// assert_eq!(NULL.normalized_and_length(), (NULL, 0.0));
+    /// ```
+    pub fn normalized_and_length(&self) -> (Self, T) {
+        let len: T = self.magn();
+        if len == T::ZERO { return (Self::ZERO, T::ZERO); }
+        (self * (T::ONE / len), len)
+    }
+
+    /// Returns a normalized copy of a [`Vector2`], or the null-vector if it has a length of `0.0`
+    /// (where [`Vector2::normalize`] would produce `NaN`s).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// assert_eq!(Vector2 { x: 0., y: -3. }.normalize_or_zero(), Vector2 { x: 0., y: -1. });
+    /// assert_eq!(NULL.normalize_or_zero(), NULL);
+    /// ```
+    #[inline]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalized_and_length().0
+    }
+
+    /// Returns a normalized copy of a [`Vector2`], or `fallback` if it has a length of `0.0`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let forward = Vector2 { x: 1., y: 0. };
+    /// assert_eq!(Vector2 { x: 0., y: -3. }.normalize_or(forward), Vector2 { x: 0., y: -1. });
+    /// assert_eq!(NULL.normalize_or(forward), forward);
+    /// ```
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        let (dir, len) = self.normalized_and_length();
+        if len == T::ZERO { fallback } else { dir }
+    }
+
     /// Calculates the square of the magnitude of a [`Vector2`].
     /// # Examples
     /// ```
@@ -102,7 +355,7 @@ impl Vector2 {
     /// assert_eq!(v1.magn_sq(), 2.5 );
     /// ```
     #[inline]
-    pub fn magn_sq(&self) -> f64 {
+    pub fn magn_sq(&self) -> T {
         self.x*self.x + self.y*self.y
     }
 
@@ -113,10 +366,65 @@ impl Vector2 {
     /// let v1 = Vector2 { x: 1.5, y: 0.5 };
     /// assert_eq!(v1.magn(), 2.5_f64.sqrt() );
     /// ```
-    pub fn magn(&self) -> f64 {
+    pub fn magn(&self) -> T {
         ( self.x*self.x + self.y*self.y ).sqrt()
     }
 
+    /// Calculates the magnitude of a [`Vector2`] with [`f64::hypot`], which avoids the intermediate overflow
+    /// (or underflow) of squaring huge (or tiny) komponents that makes [`Vector2::magn`] return infinity (or `0.0`).
+    /// It is slower than [`Vector2::magn`], so only use it where komponents can get that large.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 3e200, y: 4e200 };
+    /// assert_eq!(v.magn(), f64::INFINITY);
+    /// assert!((v.magn_stable() / 5e200 - 1.).abs() < 1e-15);
+    /// ```
+    #[inline]
+    pub fn magn_stable(&self) -> T {
+        self.x.hypot(self.y)
+    }
+
+    /// Alias of [`Vector2::magn`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 3., y: 4. };
+    /// assert_eq!(v.length(), 5.);
+    /// assert_eq!(v.magnitude(), v.length());
+    /// assert_eq!(v.length_squared(), 25.);
+    /// ```
+    #[inline(always)]
+    pub fn length(&self) -> T {
+        self.magn()
+    }
+
+    /// Alias of [`Vector2::magn_sq`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 3., y: 4. };
+    /// assert_eq!(v.length_squared(), 25.);
+    /// assert_eq!(v.length_squared(), v.magn_sq());
+    /// ```
+    #[inline(always)]
+    pub fn length_squared(&self) -> T {
+        self.magn_sq()
+    }
+
+    /// Alias of [`Vector2::magn`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 3., y: 4. };
+    /// assert_eq!(v.magnitude(), 5.);
+    /// assert_eq!(v.magnitude(), v.magn());
+    /// ```
+    #[inline(always)]
+    pub fn magnitude(&self) -> T {
+        self.magn()
+    }
+
     /// Calculates the the square of distance between two [`Vector2`]s interpreted as points.
     /// # Examples
     /// ```
@@ -125,9 +433,9 @@ impl Vector2 {
     /// let v2 = Vector2 { x: 2., y: 2. };
     /// assert_eq!(Vector2::dist_sq(&v1, &v2), 2.5 );
     /// ```
-    pub fn dist_sq(v1:&Self, v2:&Self) -> f64 {
-        let dx: f64 = v1.x-v2.x;
-        let dy: f64 = v1.y-v2.y;
+    pub fn dist_sq(v1:&Self, v2:&Self) -> T {
+        let dx: T = v1.x-v2.x;
+        let dy: T = v1.y-v2.y;
         dx*dx + dy*dy
     }
 
@@ -139,10 +447,65 @@ impl Vector2 {
     /// let v2 = Vector2 { x: 2., y: 2. };
     /// assert_eq!(Vector2::dist(&v1, &v2), 2.5_f64.sqrt() );
     /// ```
-    pub fn dist(v1:&Self, v2:&Self) -> f64 {
+    pub fn dist(v1:&Self, v2:&Self) -> T {
         Self::dist_sq(v1, v2).sqrt()
     }
 
+    /// Method form of [`Vector2::dist`], the distance between two [`Vector2`]s interpreted as points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let a = Vector2 { x: 1., y: 1. };
+    /// let b = Vector2 { x: 4., y: 5. };
+    /// assert_eq!(a.distance(&b), 5.);
+    /// ```
+    #[inline]
+    pub fn distance(&self, other: &Self) -> T {
+        Self::dist(self, other)
+    }
+
+    /// Method form of [`Vector2::dist_sq`], the square of the distance between two [`Vector2`]s interpreted as points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let a = Vector2 { x: 1., y: 1. };
+    /// let b = Vector2 { x: 4., y: 5. };
+    /// assert_eq!(a.distance_sq(&b), 25.);
+    /// ```
+    #[inline]
+    pub fn distance_sq(&self, other: &Self) -> T {
+        Self::dist_sq(self, other)
+    }
+
+    /// Calculates the manhattan (taxicab) distance `|dx| + |dy|` between two [`Vector2`]s interpreted as points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v1 = Vector2 { x: 0., y: 0. };
+    /// let v2 = Vector2 { x: 3., y: 4. };
+    /// assert_eq!(Vector2::dist(&v1, &v2), 5.);
+    /// assert_eq!(Vector2::dist_manhattan(&v1, &v2), 7.);
+    /// assert_eq!(Vector2::dist_chebyshev(&v1, &v2), 4.);
+    /// ```
+    pub fn dist_manhattan(v1: &Self, v2: &Self) -> T {
+        (v1.x - v2.x).abs() + (v1.y - v2.y).abs()
+    }
+
+    /// Calculates the chebyshev distance `max(|dx|, |dy|)` between two [`Vector2`]s interpreted as points,
+    /// i.e. the number of king moves on a grid.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v1 = Vector2 { x: 1., y: 1. };
+    /// let v2 = Vector2 { x: 4., y: 4. };
+    /// assert_eq!(Vector2::dist_chebyshev(&v1, &v2), 3.);
+    /// assert_eq!(Vector2::dist_manhattan(&v1, &v2), 6.);
+    /// assert!((Vector2::dist(&v1, &v2) - 3. * 2_f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn dist_chebyshev(v1: &Self, v2: &Self) -> T {
+        (v1.x - v2.x).abs().max((v1.y - v2.y).abs())
+    }
+
     /// Scales down a [`Vector2`] to a magnitude if it exceeds that magnitude.
     /// # Examples
     /// ```
@@ -151,9 +514,10 @@ impl Vector2 {
     /// v.clamp_max(1_f64);
     /// assert!(v.is_normalized());
     /// ```
-    pub fn clamp_max(&mut self, max: f64) {
-        let len: f64 = self.magn();
-        if len > max { *self *= max / len; }
+    pub fn clamp_max(&mut self, max: T) {
+        // compares squares first so that the common in-range case needs no sqrt
+        let len_sq: T = self.magn_sq();
+        if max < T::ZERO || len_sq > max*max { *self *= max / len_sq.sqrt(); }
     }
 
     /// Scales up a [`Vector2`] to a magnitude if it is shorter than that magnitude.
@@ -164,12 +528,75 @@ impl Vector2 {
     /// v.clamp_max(1_f64);
     /// assert!(v.is_normalized());
     /// ```
-    pub fn clamp_min(&mut self, min: f64) {
-        let len: f64 = self.magn();
-        if len < min { *self *= min / len; }
+    pub fn clamp_min(&mut self, min: T) {
+        let len_sq: T = self.magn_sq();
+        if min > T::ZERO && len_sq < min*min { *self *= min / len_sq.sqrt(); }
+    }
+
+    /// Returns a copy of a [`Vector2`] with its magnitude clamped into `[min, max]`, keeping its direction.
+    /// If `min > max` the vector is clamped to `max`. The null-vector has no direction and is returned unchanged.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let short = Vector2 { x: 0., y: 1. };
+    /// let fine = Vector2 { x: 3., y: 4. };
+    /// let long = Vector2 { x: 12., y: 16. };
+    /// assert_eq!(short.clamp_length(2., 10.), Vector2 { x: 0., y: 2. });
+    /// assert_eq!(fine.clamp_length(2., 10.), fine);
+    /// assert_eq!(long.clamp_length(2., 10.), Vector2 { x: 6., y: 8. });
+    /// assert_eq!(NULL.clamp_length(2., 10.), NULL);
+    /// ```
+    pub fn clamp_length(&self, min: T, max: T) -> Self {
+        let len: T = self.magn();
+        if len == T::ZERO { return *self; }
+        let clamped: T = len.max(min).min(max);
+        self * (clamped / len)
+    }
+
+    /// Like [`Vector2::clamp_length`], but also returns the normalized direction of the original [`Vector2`].
+    /// The direction of the null-vector is the null-vector.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 3., y: 0. };
+    /// let (clamped, dir) = v.clamp_length_with_direction(0., 1.);
+    /// assert_eq!(clamped, Vector2 { x: 1., y: 0. });
+    /// assert_eq!(dir, clamped);
+    /// ```
+    pub fn clamp_length_with_direction(&self, min: T, max: T) -> (Self, Self) {
+        let (dir, len) = self.normalized_and_length();
+        if len < min || len > max { (&dir * len.max(min).min(max), dir) }
+        else { (*self, dir) }
+    }
+
+    /// Returns a [`Vector2`] pointing in the same direction with a magnitude of `len`.
+    /// The null-vector has no direction and stays the null-vector.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 3., y: 4. };
+    /// assert!(v.with_magnitude(10.).approx_eq(&Vector2 { x: 6., y: 8. }, 1e-12));
+    /// assert_eq!(NULL.with_magnitude(10.), NULL);
+    /// ```
+    pub fn with_magnitude(&self, len: T) -> Self {
+        let (dir, _) = self.normalized_and_length();
+        dir * len
+    }
+
+    /// Sets the magnitude of a [`Vector2`] to `len`, keeping its direction. See [`Vector2::with_magnitude`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let mut v = Vector2 { x: 0., y: -2. };
+    /// v.set_magnitude(5.);
+    /// assert_eq!(v, Vector2 { x: 0., y: -5. });
+    /// ```
+    pub fn set_magnitude(&mut self, len: T) {
+        *self = self.with_magnitude(len);
     }
 
     /// Linearly interpolate between two [`Vector2`]s interpreted as points.
+    /// `factor` is not clamped, so values outside of `[0, 1]` extrapolate beyond `v1` or `v2`; see [`Vector2::lerp_clamped`].
     /// # Examples
     /// ```
     /// use cute_gorl::vector2::*;
@@ -177,15 +604,75 @@ impl Vector2 {
     /// let v2 = -v1;
     /// assert_eq!(Vector2::lerp(&v1, &v2, 0.5_f64), NULL);
     /// ```
-    pub fn lerp(v1: &Self, v2: &Self, factor: f64) -> Self {
-        let temp = 1.0 - factor;
+    pub fn lerp(v1: &Self, v2: &Self, factor: T) -> Self {
+        let temp = T::ONE - factor;
         Self {
             x: v1.x*temp + v2.x*factor,
             y: v1.y*temp + v2.y*factor
         }
     }
 
-    /// calculates the angle between two [`Vector2`]s.
+    /// The point halfway between two [`Vector2`]s interpreted as points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let a = Vector2 { x: 1., y: -2. };
+    /// let b = Vector2 { x: 3., y: 4. };
+    /// assert_eq!(Vector2::midpoint(&a, &b), Vector2 { x: 2., y: 1. });
+    /// ```
+    #[inline]
+    pub fn midpoint(a: &Self, b: &Self) -> Self {
+        Self::lerp(a, b, T::HALF)
+    }
+
+    /// Moves a [`Vector2`] interpreted as a point in place, linearly interpolating it towards `target` by `t`.
+    /// Same as `*self = Vector2::lerp(self, target, t)`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let mut pos = Vector2 { x: 0., y: 2. };
+    /// pos.lerp_towards(&Vector2 { x: 4., y: 0. }, 0.25);
+    /// assert_eq!(pos, Vector2 { x: 1., y: 1.5 });
+    /// ```
+    #[inline]
+    pub fn lerp_towards(&mut self, target: &Self, t: T) {
+        *self = Self::lerp(self, target, t);
+    }
+
+    /// Linearly interpolate between two [`Vector2`]s interpreted as points, clamping `factor` into `[0, 1]` first,
+    /// so the result always lies on the segment between `v1` and `v2`. Use [`Vector2::lerp`] to extrapolate.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 1., y: 2. };
+    /// assert_eq!(Vector2::lerp_clamped(&NULL, &v, 2.), v);
+    /// assert_eq!(Vector2::lerp_clamped(&NULL, &v, -1.), NULL);
+    /// ```
+    pub fn lerp_clamped(v1: &Self, v2: &Self, factor: T) -> Self {
+        Self::lerp(v1, v2, factor.clamp(T::ZERO, T::ONE))
+    }
+
+    /// Moves a [`Vector2`] interpreted as a point towards `target` by at most `max_delta`.
+    /// Snaps exactly to `target` once it is within reach; a `max_delta <= 0` leaves the point where it is.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 0., y: 0. };
+    /// let target = Vector2 { x: 3., y: 4. };
+    /// assert_eq!(v.move_towards(&target, 2.5), Vector2 { x: 1.5, y: 2. });
+    /// assert_eq!(v.move_towards(&target, 10.), target);
+    /// ```
+    pub fn move_towards(&self, target: &Self, max_delta: T) -> Self {
+        if max_delta <= T::ZERO { return *self; }
+        let delta: Self = target - self;
+        let dist_sq: T = delta.magn_sq();
+        if dist_sq <= max_delta*max_delta { return *target; }
+        self + &(&delta * (max_delta / dist_sq.sqrt()))
+    }
+
+    /// calculates the (unsigned) angle between two [`Vector2`]s, in `[0, PI]`.
+    /// Like [`Vector3::angle_between`](crate::vector3::Vector3::angle_between) it does not tell which way round the angle goes;
+    /// see [`Vector2::signed_angle_between`] for that.
     /// # Examples
     /// ```
     /// use cute_gorl::vector2::*;
@@ -193,16 +680,28 @@ impl Vector2 {
     /// let v1 = Vector2 { x: 1., y: 1. };
     /// let v2 = -v1;
     /// assert_eq!( Vector2::angle_between(&v1, &v2), PI );
+    /// assert_eq!( Vector2::angle_between(&v1, &v1.rotate_left()), 0.5*PI );
     /// ```
-    pub fn angle_between(v1: &Self, v2: &Self) -> f64 {
-        let o: f64 = v1.y * v2.x - v1.x * v2.y;
-        let a: f64 = v1.x * v2.x + v1.y * v2.y;
-        let res: f64 = (o / a).atan();
-        if o < 0.0 {
-            if a <= 0.0 { PI - res } else { -res }
-        } else {
-            if a <  0.0 { PI + res } else { res }
-        }
+    pub fn angle_between(v1: &Self, v2: &Self) -> T {
+        Self::signed_angle_between(v1, v2).abs()
+    }
+
+    /// Calculates the signed angle from one [`Vector2`] to another, in `(-PI, PI]`.
+    /// The angle is positive if `v2` lies anti-clockwise of `v1` and negative if it lies clockwise,
+    /// so swapping the arguments flips the sign.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// use std::f64::consts::PI;
+    /// let v1 = Vector2 { x: 1., y: 0. };
+    /// let v2 = Vector2 { x: 0., y: 3. };
+    /// assert_eq!( Vector2::signed_angle_between(&v1, &v2), 0.5*PI );
+    /// assert_eq!( Vector2::signed_angle_between(&v2, &v1), -0.5*PI );
+    /// ```
+    pub fn signed_angle_between(v1: &Self, v2: &Self) -> T {
+        let angle: T = Self::perp_dot(v1, v2).atan2(Self::scalar(v1, v2));
+        // opposite vectors can come out as -PI depending on the sign of a zero perp dot
+        if angle == -T::PI { T::PI } else { angle }
     }
 
     /// Rotate (anti-clockwise) a [`Vector2`] by an angle.
@@ -211,20 +710,61 @@ impl Vector2 {
     /// use cute_gorl::vector2::*;
     /// use std::f64::consts::PI;
     /// let mut v = Vector2 { x: 1., y: 1. };
-    /// assert_eq!(
-    ///     v.rotate(0.5*PI),
-    ///     Vector2 { x: -1., y: 1. }
-    /// );
-    /// ```
-    pub fn rotate(&self, angle: f64) -> Self {
-        let sin_a: f64 = angle.sin();
-        let cos_a: f64 = math::cosq(sin_a);
+    /// assert!(v.rotate(0.5*PI).approx_eq(&Vector2 { x: -1., y: 1. }, 1e-12));
+    /// assert!(v.rotate(PI).approx_eq(&Vector2 { x: -1., y: -1. }, 1e-12));
+    /// ```
+    pub fn rotate(&self, angle: T) -> Self {
+        let (sin_a, cos_a) = angle.sin_cos();
+        self.rotate_by(sin_a, cos_a)
+    }
+
+    /// Rotate (anti-clockwise) a [`Vector2`] by an angle given as its precomputed sine and cosine,
+    /// so that rotating many vectors by the same angle only needs to calculate them once.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let (sin_a, cos_a) = 0.75_f64.sin_cos();
+    /// let v = Vector2 { x: 2., y: -1. };
+    /// assert_eq!(v.rotate_by(sin_a, cos_a), v.rotate(0.75));
+    /// ```
+    #[inline]
+    pub fn rotate_by(&self, sin_a: T, cos_a: T) -> Self {
         Self {
             x: self.x*cos_a - self.y*sin_a,
             y: self.y*cos_a + self.x*sin_a
         }
     }
 
+    /// Rotate (anti-clockwise) a [`Vector2`] interpreted as a point by an angle around a `pivot` point.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// use std::f64::consts::PI;
+    /// let p = Vector2 { x: 3., y: 1. };
+    /// let pivot = Vector2 { x: 2., y: 1. };
+    /// assert!(p.rotate_around(&pivot, 0.5*PI).approx_eq(&Vector2 { x: 2., y: 2. }, 1e-12));
+    /// ```
+    pub fn rotate_around(&self, pivot: &Self, angle: T) -> Self {
+        (self - pivot).rotate(angle) + pivot
+    }
+
+    /// Scales a [`Vector2`] interpreted as a point away from (or towards) a `pivot` point,
+    /// with each axis scaled by the matching komponent of `factors`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let p = Vector2 { x: 3., y: 2. };
+    /// let pivot = Vector2 { x: 1., y: 1. };
+    /// let factors = Vector2 { x: 2., y: 2. };
+    /// assert_eq!(p.scale_around(&pivot, &factors), Vector2 { x: 5., y: 3. });
+    /// ```
+    pub fn scale_around(&self, pivot: &Self, factors: &Self) -> Self {
+        Self {
+            x: pivot.x + (self.x - pivot.x) * factors.x,
+            y: pivot.y + (self.y - pivot.y) * factors.y
+        }
+    }
+
     /// Rotate a [`Vector2`] by 90 degrees.
     #[inline(always)]
     pub fn rotate_right(&self) -> Self {
@@ -246,93 +786,874 @@ impl Vector2 {
     /// assert_eq!(v.reflect(&n), Vector2 { x: 1., y: -1. });
     /// ```
     pub fn reflect(&self, n0: &Self) -> Self {
-        let factor: f64 = 2.0 * &Self::scalar(self, n0);
+        let factor: T = T::TWO * Self::scalar(self, n0);
         let temp: Self = n0 * factor;
         self - &temp
     }
-}
 
-impl Neg for Vector2 {
-    type Output = Vector2;
-    fn neg(self) -> Self::Output {
-        Self::Output { x: -self.x, y: -self.y }
-    }
-}
-impl PartialEq for Vector2{
-    fn eq(&self, other: &Vector2) -> bool{
-        (self.x == other.x) &&
-        (self.y == other.y)
+    /// Reflects a [`Vector2`] velocity off of a surface with a certain normal, but only if it is moving into the surface,
+    /// i.e. if its dot product with the normal is negative. Also returns whether it was reflected,
+    /// so a velocity that is already moving away is not bounced back into the surface a second time.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let n = Vector2 { x: 0., y: 1. };
+    /// assert_eq!(Vector2 { x: 1., y: -2. }.reflect_if_incoming(&n), (Vector2 { x: 1., y: 2. }, true));
+    /// assert_eq!(Vector2 { x: 1., y: 2. }.reflect_if_incoming(&n), (Vector2 { x: 1., y: 2. }, false));
+    /// ```
+    pub fn reflect_if_incoming(&self, n0: &Self) -> (Self, bool) {
+        if Self::scalar(self, n0) < T::ZERO { (self.reflect(n0), true) } else { (*self, false) }
     }
-    fn ne(&self, other: &Vector2) -> bool{
-        (self.x != other.x) &&
-        (self.y != other.y)
+
+    /// Mirrors a [`Vector2`] interpreted as a point across the line through `line_point` along `line_dir`.
+    /// Unlike [`Vector2::reflect`] the line does not need to go through the origin, and `line_dir`
+    /// does not need to be normalized, but it must not be the null-vector.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let p = Vector2 { x: 3., y: 2. };
+    /// let on_line = Vector2 { x: 0., y: 1. };
+    /// let dir = Vector2 { x: 2., y: 0. };
+    /// assert_eq!(p.reflect_point_across_line(&on_line, &dir), Vector2 { x: 3., y: 0. });
+    /// ```
+    pub fn reflect_point_across_line(&self, line_point: &Self, line_dir: &Self) -> Self {
+        let offset: Self = self - line_point;
+        let along: Self = line_dir * (Self::scalar(&offset, line_dir) / line_dir.magn_sq());
+        // the foot of the perpendicular is line_point + along, and the mirror image lies as far beyond it
+        (line_point + along) * T::TWO - self
     }
-}
-impl Add for &Vector2{
-    type Output = Vector2;
-    fn add(self, other: &Vector2) -> Self::Output {
-        Vector2 { x: self.x + other.x, y: self.y + other.y }
+
+    /// The komponent-wise minimum of two [`Vector2`]s.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v1 = Vector2 { x: 1., y: -3. };
+    /// let v2 = Vector2 { x: -2., y: 4. };
+    /// assert_eq!(Vector2::min(&v1, &v2), Vector2 { x: -2., y: -3. });
+    /// ```
+    #[inline]
+    pub fn min(v1: &Self, v2: &Self) -> Self {
+        Self { x: v1.x.min(v2.x), y: v1.y.min(v2.y) }
+    }
+
+    /// The komponent-wise maximum of two [`Vector2`]s.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v1 = Vector2 { x: 1., y: -3. };
+    /// let v2 = Vector2 { x: -2., y: 4. };
+    /// assert_eq!(Vector2::max(&v1, &v2), Vector2 { x: 1., y: 4. });
+    /// ```
+    #[inline]
+    pub fn max(v1: &Self, v2: &Self) -> Self {
+        Self { x: v1.x.max(v2.x), y: v1.y.max(v2.y) }
+    }
+
+    /// The komponent-wise absolute value of a [`Vector2`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: -1.5, y: 2. };
+    /// assert_eq!(v.abs(), Vector2 { x: 1.5, y: 2. });
+    /// ```
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Self { x: self.x.abs(), y: self.y.abs() }
+    }
+
+    /// Rounds each komponent of a [`Vector2`] down, towards negative infinity.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 1.7, y: -1.2 };
+    /// assert_eq!(v.floor(), Vector2 { x: 1., y: -2. });
+    /// ```
+    #[inline]
+    pub fn floor(&self) -> Self {
+        Self { x: self.x.floor(), y: self.y.floor() }
+    }
+
+    /// Rounds each komponent of a [`Vector2`] up, towards positive infinity.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 1.2, y: -1.7 };
+    /// assert_eq!(v.ceil(), Vector2 { x: 2., y: -1. });
+    /// ```
+    #[inline]
+    pub fn ceil(&self) -> Self {
+        Self { x: self.x.ceil(), y: self.y.ceil() }
+    }
+
+    /// Rounds each komponent of a [`Vector2`] to the nearest integer. Like [`f64::round`], halfway cases round away from `0.0`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 2.5, y: -2.5 };
+    /// assert_eq!(v.round(), Vector2 { x: 3., y: -3. });
+    /// ```
+    #[inline]
+    pub fn round(&self) -> Self {
+        Self { x: self.x.round(), y: self.y.round() }
+    }
+
+    /// Calculates the reciprocal `1.0 / k` of each komponent of a [`Vector2`].
+    /// Like for [`f64`], a komponent of `0.0` yields an infinite komponent (`-0.0` yields negative infinity).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 2., y: -0.25 };
+    /// assert_eq!(v.recip(), Vector2 { x: 0.5, y: -4. });
+    /// assert_eq!(Vector2 { x: 0., y: 1. }.recip().x, f64::INFINITY);
+    /// ```
+    #[inline]
+    pub fn recip(&self) -> Self {
+        Self { x: self.x.recip(), y: self.y.recip() }
+    }
+
+    /// Returns the sign of each komponent of a [`Vector2`] as `1.0` or `-1.0`, following [`f64::signum`]
+    /// (so `0.0` yields `1.0` and `-0.0` yields `-1.0`).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 3.5, y: -0.1 };
+    /// assert_eq!(v.signum(), Vector2 { x: 1., y: -1. });
+    /// ```
+    #[inline]
+    pub fn signum(&self) -> Self {
+        Self { x: self.x.signum(), y: self.y.signum() }
+    }
+
+    /// Offsets a [`Vector2`] by a pseudo-random amount in `[-amount, amount]` along each axis.
+    /// The offset only depends on `seed`, so the same seed always jitters the same way, e.g. for reproducible scattering.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2f64 { x: 1., y: 2. };
+    /// let j = v.jitter(42, 0.5);
+    /// assert_eq!(j, v.jitter(42, 0.5));
+    /// assert!((j.x - v.x).abs() <= 0.5 && (j.y - v.y).abs() <= 0.5);
+    /// ```
+    pub fn jitter(&self, seed: u64, amount: T) -> Self {
+        let mut state: u64 = seed;
+        let mut offset = || T::from_f64(math::signed_unit(math::splitmix64(&mut state))) * amount;
+        Self {
+            x: self.x + offset(),
+            y: self.y + offset()
+        }
+    }
+
+    /// Calculates the distance from a [`Vector2`] interpreted as a point to the nearest point on the segment from `a` to `b`.
+    /// If `a` and `b` coincide this is the distance to that point.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let a = Vector2 { x: 0., y: 0. };
+    /// let b = Vector2 { x: 4., y: 0. };
+    /// assert_eq!(Vector2 { x: 1., y: 2. }.dist_to_segment(&a, &b), 2.);
+    /// assert_eq!(Vector2 { x: 7., y: 4. }.dist_to_segment(&a, &b), 5.);
+    /// ```
+    pub fn dist_to_segment(&self, a: &Self, b: &Self) -> T {
+        let ab: Self = b - a;
+        let len_sq: T = ab.magn_sq();
+        if len_sq == T::ZERO { return Self::dist(self, a); }
+        let t: T = (Self::scalar(&(self - a), &ab) / len_sq).clamp(T::ZERO, T::ONE);
+        Self::dist(self, &(a + ab * t))
+    }
+
+    /// Calculates the distance from a [`Vector2`] interpreted as a point to the infinite line through `a` and `b`.
+    /// If `a` and `b` coincide this is the distance to that point.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let a = Vector2 { x: 0., y: 0. };
+    /// let b = Vector2 { x: 4., y: 0. };
+    /// assert_eq!(Vector2 { x: 7., y: 4. }.dist_to_line(&a, &b), 4.);
+    /// ```
+    pub fn dist_to_line(&self, a: &Self, b: &Self) -> T {
+        let ab: Self = b - a;
+        let len: T = ab.magn();
+        if len == T::ZERO { return Self::dist(self, a); }
+        let ap: Self = self - a;
+        (ab.x * ap.y - ab.y * ap.x).abs() / len
+    }
+
+    /// Calculates the area of the triangle with the corners `a`, `b` and `c`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let a = Vector2 { x: 0., y: 0. };
+    /// let b = Vector2 { x: 4., y: 0. };
+    /// let c = Vector2 { x: 1., y: 3. };
+    /// assert_eq!(Vector2::triangle_area(&a, &b, &c), 6.);
+    /// assert_eq!(Vector2::triangle_area(&a, &c, &b), 6.);
+    /// ```
+    pub fn triangle_area(a: &Self, b: &Self, c: &Self) -> T {
+        T::HALF * Self::perp_dot(&(b - a), &(c - a)).abs()
+    }
+
+    /// Calculates the barycentric coordinates `(u, v, w)` of a point `p` with respect to the triangle `a`, `b`, `c`,
+    /// so that `p = a*u + b*v + c*w`. The weights always sum to `1`; they are all in `[0, 1]` for points inside
+    /// of the triangle, and at least one is negative for points outside of it. The triangle must not be degenerate.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let a = Vector2 { x: 0., y: 0. };
+    /// let b = Vector2 { x: 4., y: 0. };
+    /// let c = Vector2 { x: 0., y: 4. };
+    /// assert_eq!(Vector2::barycentric(&Vector2 { x: 1., y: 2. }, &a, &b, &c), (0.25, 0.25, 0.5));
+    /// let (u, _, _) = Vector2::barycentric(&Vector2 { x: 4., y: 4. }, &a, &b, &c);
+    /// assert!(u < 0.);
+    /// ```
+    pub fn barycentric(p: &Self, a: &Self, b: &Self, c: &Self) -> (T, T, T) {
+        let inv_area: T = T::ONE / Self::perp_dot(&(b - a), &(c - a));
+        let (pa, pb, pc) = (a - p, b - p, c - p);
+        let u: T = Self::perp_dot(&pb, &pc) * inv_area;
+        let v: T = Self::perp_dot(&pc, &pa) * inv_area;
+        (u, v, T::ONE - u - v)
+    }
+
+    /// Returns a copy of a [`Vector2`] with the x component replaced.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 1., y: 2. };
+    /// assert_eq!(v.with_x(5.), Vector2 { x: 5., y: 2. });
+    /// ```
+    #[inline]
+    pub fn with_x(self, x: T) -> Self {
+        Self { x, ..self }
+    }
+
+    /// Returns a copy of a [`Vector2`] with the y component replaced.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 1., y: 2. };
+    /// assert_eq!(v.with_y(0.), Vector2 { x: 1., y: 0. });
+    /// ```
+    #[inline]
+    pub fn with_y(self, y: T) -> Self {
+        Self { y, ..self }
+    }
+
+    /// Extends a [`Vector2`] to a [`Vector3`] in the xy-plane, with a z component of `0.0`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// use cute_gorl::vector3::Vector3;
+    /// let v = Vector2 { x: 1., y: 2. };
+    /// assert_eq!(v.xy0(), Vector3 { x: 1., y: 2., z: 0. });
+    /// ```
+    #[inline]
+    pub fn xy0(&self) -> Vector3<T> {
+        Vector3 { x: self.x, y: self.y, z: T::ZERO }
+    }
+
+    /// Extends a [`Vector2`] to a [`Vector3`] in the xz-plane, with a y component of `0.0`.
+    /// Handy for turning 2D positions into positions on a ground plane.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// use cute_gorl::vector3::Vector3;
+    /// let v = Vector2 { x: 1., y: 2. };
+    /// assert_eq!(v.x0y(), Vector3 { x: 1., y: 0., z: 2. });
+    /// ```
+    #[inline]
+    pub fn x0y(&self) -> Vector3<T> {
+        Vector3 { x: self.x, y: T::ZERO, z: self.y }
+    }
+
+    /// Determines whether or not all components of a [`Vector2`] are finite, i.e. neither infinite nor `NaN`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// assert!(Vector2 { x: 1., y: -2. }.is_finite());
+    /// assert!( !(Vector2 { x: f64::INFINITY, y: 0. }.is_finite()) );
+    /// ```
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Determines whether or not any component of a [`Vector2`] is `NaN`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// assert!(Vector2 { x: 0., y: f64::NAN }.is_nan());
+    /// assert!( !(Vector2 { x: f64::INFINITY, y: 0. }.is_nan()) );
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    /// Views the components of a [`Vector2`] as a slice `[x, y]`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 1., y: 2. };
+    /// assert_eq!(v.as_slice(), &[1., 2.]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.as_ref()
+    }
+
+    /// Compares two [`Vector2`]s lexicographically like [`PartialOrd`], but as a total order
+    /// following [`f64::total_cmp`], so `NaN`s are sorted consistently (and `-0.0` comes before `0.0`).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let mut v = vec![Vector2 { x: f64::NAN, y: 0. }, Vector2 { x: 1., y: 0. }, Vector2 { x: -1., y: 2. }];
+    /// v.sort_by(|a, b| a.total_cmp(b));
+    /// assert_eq!(v[0], Vector2 { x: -1., y: 2. });
+    /// assert!(v[2].is_nan());
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.x.total_cmp(&other.x)
+            .then_with(|| self.y.total_cmp(&other.y))
+    }
+
+    /// Clamps each komponent of a [`Vector2`] to the range between the matching komponents of `min` and `max`,
+    /// e.g. to keep a point inside of a box. Unlike [`f64::clamp`] this never panics:
+    /// if `min` is greater than `max` on an axis, that komponent is set to the one of `max`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let min = Vector2 { x: 0., y: 0. };
+    /// let max = Vector2 { x: 10., y: 5. };
+    /// assert_eq!(Vector2 { x: 3., y: 4. }.clamp_components(&min, &max), Vector2 { x: 3., y: 4. });
+    /// assert_eq!(Vector2 { x: 3., y: 9. }.clamp_components(&min, &max), Vector2 { x: 3., y: 5. });
+    /// assert_eq!(Vector2 { x: -3., y: 9. }.clamp_components(&min, &max), Vector2 { x: 0., y: 5. });
+    /// ```
+    pub fn clamp_components(&self, min: &Self, max: &Self) -> Self {
+        Self {
+            x: self.x.max(min.x).min(max.x),
+            y: self.y.max(min.y).min(max.y)
+        }
+    }
+
+    /// Interpolates along a (uniform) Catmull-Rom spline, which passes through all of its control points.
+    /// Returns the point at `t` on the segment from `p1` (`t = 0`) to `p2` (`t = 1`); `p0` and `p3` are the neighbouring
+    /// control points that shape it. The tangent at each control point is half the difference of its neighbours,
+    /// i.e. `(p2 - p0) / 2` at `p1` and `(p3 - p1) / 2` at `p2`, so consecutive segments join smoothly.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let p0 = Vector2 { x: 0., y: 0. };
+    /// let p1 = Vector2 { x: 1., y: 1. };
+    /// let p2 = Vector2 { x: 2., y: 1. };
+    /// let p3 = Vector2 { x: 3., y: 0. };
+    /// assert_eq!(Vector2::catmull_rom(&p0, &p1, &p2, &p3, 0.5), Vector2 { x: 1.5, y: 1.125 });
+    /// ```
+    pub fn catmull_rom(p0: &Self, p1: &Self, p2: &Self, p3: &Self, t: T) -> Self {
+        let (t2, t3) = (t*t, t*t*t);
+        // cubic hermite basis, exact at both ends
+        let h00: T = T::TWO*t3 - T::from_f64(3.)*t2 + T::ONE;
+        let h10: T = t3 - T::TWO*t2 + t;
+        let h01: T = -T::TWO*t3 + T::from_f64(3.)*t2;
+        let h11: T = t3 - t2;
+        let m1: Self = (p2 - p0) * T::HALF;
+        let m2: Self = (p3 - p1) * T::HALF;
+        p1 * h00 + m1 * h10 + p2 * h01 + m2 * h11
+    }
+
+    /// Evaluates the quadratic Bézier curve from `p0` to `p2` with the control point `p1` at `t`, using de Casteljau's algorithm.
+    /// The curve starts exactly at `p0` for `t = 0` and ends exactly at `p2` for `t = 1`, but usually does not pass through `p1`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let p0 = Vector2 { x: 0., y: 0. };
+    /// let p1 = Vector2 { x: 1., y: 2. };
+    /// let p2 = Vector2 { x: 2., y: 0. };
+    /// assert_eq!(Vector2::bezier_quadratic(&p0, &p1, &p2, 0.5), Vector2 { x: 1., y: 1. });
+    /// assert_eq!(Vector2::bezier_quadratic(&p0, &p1, &p2, 1.), p2);
+    /// ```
+    pub fn bezier_quadratic(p0: &Self, p1: &Self, p2: &Self, t: T) -> Self {
+        let a: Self = Self::lerp(p0, p1, t);
+        let b: Self = Self::lerp(p1, p2, t);
+        Self::lerp(&a, &b, t)
+    }
+
+    /// Evaluates the cubic Bézier curve from `p0` to `p3` with the control points `p1` and `p2` at `t`, using de Casteljau's algorithm.
+    /// The curve starts exactly at `p0` for `t = 0` and ends exactly at `p3` for `t = 1`,
+    /// leaving `p0` towards `p1` and arriving at `p3` from the direction of `p2`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let p0 = Vector2 { x: 0., y: 0. };
+    /// let p1 = Vector2 { x: 0., y: 4. };
+    /// let p2 = Vector2 { x: 4., y: 4. };
+    /// let p3 = Vector2 { x: 4., y: 0. };
+    /// assert_eq!(Vector2::bezier_cubic(&p0, &p1, &p2, &p3, 0.5), Vector2 { x: 2., y: 3. });
+    /// assert_eq!(Vector2::bezier_cubic(&p0, &p1, &p2, &p3, 0.), p0);
+    /// ```
+    pub fn bezier_cubic(p0: &Self, p1: &Self, p2: &Self, p3: &Self, t: T) -> Self {
+        let a: Self = Self::bezier_quadratic(p0, p1, p2, t);
+        let b: Self = Self::bezier_quadratic(p1, p2, p3, t);
+        Self::lerp(&a, &b, t)
+    }
+
+    /// Moves a [`Vector2`] towards a `target` like a critically damped spring, e.g. for a camera following a player.
+    /// `velocity` is the current speed of the movement and gets updated in place, so the same variable must be passed in every frame.
+    /// `smooth_time` is roughly the time it takes to reach the target and is clamped to at least `0.0001`, `dt` is the time step.
+    /// The result never overshoots the target.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let target = Vector2 { x: 10., y: 0. };
+    /// let mut pos = NULL;
+    /// let mut velocity = NULL;
+    /// for _ in 0..120 {
+    ///     pos = Vector2::smooth_damp(&pos, &target, &mut velocity, 0.3, 1. / 60.);
+    /// }
+    /// assert!(Vector2::dist(&pos, &target) < 0.01);
+    /// ```
+    pub fn smooth_damp(current: &Self, target: &Self, velocity: &mut Self, smooth_time: T, dt: T) -> Self {
+        let omega: T = T::TWO / smooth_time.max(T::from_f64(0.0001));
+        let x: T = omega * dt;
+        // approximation of exp(-x) that is accurate enough for small steps
+        let decay: T = T::ONE / (T::ONE + x + T::from_f64(0.48)*x*x + T::from_f64(0.235)*x*x*x);
+        let change: Self = current - target;
+        let temp: Self = (*velocity + change * omega) * dt;
+        *velocity = (*velocity - temp * omega) * decay;
+        let res: Self = target + (change + temp) * decay;
+        // stop at the target if the step would have moved past it
+        if Self::scalar(&(target - current), &(res - target)) > T::ZERO {
+            *velocity = Self::ZERO;
+            return *target;
+        }
+        res
+    }
+
+    /// Converts a [`Vector2`] to an `[f32; 2]`, e.g. for uploading it to a GPU buffer.
+    /// Each komponent is rounded to the nearest `f32`, which keeps only about 7 significant digits,
+    /// and values beyond the range of `f32` become infinite.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 1.5, y: 0.1 };
+    /// assert_eq!(v.to_f32_array(), [1.5_f32, 0.1_f32]);
+    /// ```
+    #[inline]
+    pub fn to_f32_array(&self) -> [f32; 2] {
+        [self.x.to_f64() as f32, self.y.to_f64() as f32]
+    }
+
+    /// Converts a [`Vector2`] to one with another komponent type, e.g. to a [`Vector2f32`] for a vertex buffer.
+    /// Going from `f64` to `f32` rounds each komponent to the nearest `f32`, the other way round is exact.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let v = Vector2 { x: 1.5, y: 0.1 };
+    /// let small: Vector2f32 = v.cast();
+    /// assert_eq!(small, Vector2 { x: 1.5_f32, y: 0.1_f32 });
+    /// assert_eq!(small.cast::<f64>().x, 1.5);
+    /// ```
+    #[inline]
+    pub fn cast<U: Scalar>(&self) -> Vector2<U> {
+        Vector2 { x: U::from_f64(self.x.to_f64()), y: U::from_f64(self.y.to_f64()) }
+    }
+
+    /// Calculates the smallest axis-aligned box containing a number of [`Vector2`]s interpreted as points,
+    /// as its `(min, max)` corners, or `None` if there are no points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let points = [Vector2 { x: 1., y: -2. }, Vector2 { x: -3., y: 0. }, Vector2 { x: 2., y: 5. }];
+    /// assert_eq!(Vector2::bounding_box(&points), Some((Vector2 { x: -3., y: -2. }, Vector2 { x: 2., y: 5. })));
+    /// assert_eq!(Vector2f64::bounding_box(&[]), None);
+    /// ```
+    pub fn bounding_box(points: &[Self]) -> Option<(Self, Self)> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold((*first, *first), |(min, max), p| (Self::min(&min, p), Self::max(&max, p))))
+    }
+
+    /// Interpolates along a path through a number of [`Vector2`]s interpreted as waypoints, with `t` going from `0.0`
+    /// at the first waypoint to `1.0` at the last one. Each segment gets the same share of `t` no matter how long it is
+    /// (see [`polyline_point_at_distance`](crate::geometry::polyline_point_at_distance) for moving at a constant speed).
+    /// `t` is clamped to `[0, 1]`; `None` if there are no waypoints.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// let path = [Vector2 { x: 0., y: 0. }, Vector2 { x: 1., y: 0. }, Vector2 { x: 1., y: 5. }];
+    /// assert_eq!(Vector2::path_lerp(&path, 0.25), Some(Vector2 { x: 0.5, y: 0. }));
+    /// assert_eq!(Vector2::path_lerp(&path, 0.75), Some(Vector2 { x: 1., y: 2.5 }));
+    /// ```
+    pub fn path_lerp(points: &[Self], t: T) -> Option<Self> {
+        let segments: usize = points.len().checked_sub(1)?;
+        if segments == 0 { return Some(points[0]); }
+        let progress: T = t.clamp(T::ZERO, T::ONE) * T::from_f64(segments as f64);
+        let i: usize = (progress.floor().to_f64() as usize).min(segments - 1);
+        Some(Self::lerp(&points[i], &points[i + 1], progress - T::from_f64(i as f64)))
+    }
+
+    /// The index of the largest komponent of a [`Vector2`]: `0` for x and `1` for y. On a tie the lower index wins.
+    /// Komponents are compared by value, so use [`Vector2::abs`] first to find the axis a direction is closest to.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// assert_eq!(Vector2 { x: 1., y: 4. }.max_axis(), 1);
+    /// assert_eq!(Vector2 { x: 2., y: 2. }.max_axis(), 0);
+    /// ```
+    #[inline]
+    pub fn max_axis(&self) -> usize {
+        if self.y > self.x { 1 } else { 0 }
+    }
+
+    /// The index of the smallest komponent of a [`Vector2`]: `0` for x and `1` for y. On a tie the lower index wins.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// assert_eq!(Vector2 { x: 1., y: -4. }.min_axis(), 1);
+    /// assert_eq!(Vector2 { x: 2., y: 2. }.min_axis(), 0);
+    /// ```
+    #[inline]
+    pub fn min_axis(&self) -> usize {
+        if self.y < self.x { 1 } else { 0 }
+    }
+
+    /// A random normalized [`Vector2`], uniformly distributed over the unit circle. Only available with the `rand` feature.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// assert!(Vector2::random_unit(&mut rng).is_normalized_eps(1e-12));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_unit(rng: &mut impl rand::Rng) -> Self {
+        let (s, c) = rng.gen_range(0.0..2.*PI).sin_cos();
+        Vector2 { x: T::from_f64(c), y: T::from_f64(s) }
     }
 }
-impl Sub for &Vector2{
-    type Output = Vector2;
-    fn sub(self, other: &Vector2) -> Self::Output {
+
+// the few methods that are tied to f64, e.g. through the angle types
+impl Vector2 {
+    /// Calculates the angle between two [`Vector2`]s as an [`AngleInfo`], in radians, degrees and signed, all at once.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// use std::f64::consts::PI;
+    /// let v1 = Vector2 { x: 1., y: 0. };
+    /// let v2 = Vector2 { x: 0., y: -2. };
+    /// let info = Vector2::angle_info(&v1, &v2);
+    /// assert_eq!(info.radians, 0.5*PI);
+    /// assert_eq!(info.degrees, 90.);
+    /// assert_eq!(info.signed, -0.5*PI);
+    /// ```
+    pub fn angle_info(v1: &Self, v2: &Self) -> AngleInfo {
+        let signed: f64 = Self::signed_angle_between(v1, v2);
+        let radians: f64 = signed.abs();
+        AngleInfo { radians, degrees: radians.to_degrees(), signed }
+    }
+
+    /// Rotate (anti-clockwise) a [`Vector2`] by an angle in [`Degrees`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// use cute_gorl::angle::Degrees;
+    /// let v = Vector2 { x: 2., y: 1. };
+    /// assert!(v.rotate_deg(Degrees(90.)).approx_eq(&Vector2 { x: -1., y: 2. }, 1e-12));
+    /// ```
+    #[inline]
+    pub fn rotate_deg(&self, angle: Degrees) -> Self {
+        let Radians(radians) = angle.into();
+        self.rotate(radians)
+    }
+
+    /// Snaps the direction of a [`Vector2`] to the nearest of 4 (east, north, west, south) or 8 (including the diagonals)
+    /// directions, returning a unit vector along it, e.g. for tile-based movement. The null-vector stays the null-vector.
+    /// # Panics
+    /// If `directions` is neither 4 nor 8.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector2::*;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    /// let v = Vector2 { x: 2., y: 1.5 };
+    /// assert_eq!(v.snap_to_cardinal(4), Vector2 { x: 1., y: 0. });
+    /// assert_eq!(v.snap_to_cardinal(8), Vector2 { x: FRAC_1_SQRT_2, y: FRAC_1_SQRT_2 });
+    /// ```
+    pub fn snap_to_cardinal(&self, directions: usize) -> Self {
+        use std::f64::consts::FRAC_1_SQRT_2 as D;
+        assert!(directions == 4 || directions == 8, "snap_to_cardinal supports 4 or 8 directions, not {}", directions);
+        if self.is_nullvector() { return NULL; }
+        // anti-clockwise, starting east
+        const COMPASS: [Vector2; 8] = [
+            Vector2 { x: 1., y: 0. }, Vector2 { x: D, y: D },
+            Vector2 { x: 0., y: 1. }, Vector2 { x: -D, y: D },
+            Vector2 { x: -1., y: 0. }, Vector2 { x: -D, y: -D },
+            Vector2 { x: 0., y: -1. }, Vector2 { x: D, y: -D }
+        ];
+        let sector: f64 = self.y.atan2(self.x) / (2. * PI / directions as f64);
+        let index: usize = (sector.round() as i64).rem_euclid(directions as i64) as usize;
+        COMPASS[index * (8 / directions)]
+    }
+}
+
+impl<T: Scalar> Neg for Vector2<T> {
+    type Output = Vector2<T>;
+    fn neg(self) -> Self::Output {
+        Self::Output { x: -self.x, y: -self.y }
+    }
+}
+/// Negates a borrowed [`Vector2`] into a new one.
+/// # Examples
+/// ```
+/// use cute_gorl::vector2::*;
+/// let v = Vector2 { x: 1., y: -2. };
+/// let w = Vector2 { x: 0.5, y: 0.5 };
+/// assert_eq!(-&v + w, Vector2 { x: -0.5, y: 2.5 });
+/// ```
+impl<T: Scalar> Neg for &Vector2<T> {
+    type Output = Vector2<T>;
+    fn neg(self) -> Self::Output {
+        Vector2 { x: -self.x, y: -self.y }
+    }
+}
+impl<T: Scalar> fmt::Debug for Vector2<T> {
+    /// Prints the compact `Vec2(x, y)`, or the usual struct form with `{:#?}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Vector2")
+                .field("x", &self.x)
+                .field("y", &self.y)
+                .finish()
+        } else {
+            // the komponents go through the same formatter, so flags like `{:.2?}` apply to each of them
+            f.write_str("Vec2(")?;
+            fmt::Display::fmt(&self.x, f)?;
+            f.write_str(", ")?;
+            fmt::Display::fmt(&self.y, f)?;
+            f.write_str(")")
+        }
+    }
+}
+impl<T: Scalar> PartialEq for Vector2<T>{
+    fn eq(&self, other: &Vector2<T>) -> bool{
+        (self.x == other.x) &&
+        (self.y == other.y)
+    }
+}
+/// Orders [`Vector2`]s lexicographically, by x first and then by y.
+/// This ordering has no geometric meaning, it is only there to sort vectors into a canonical order, e.g. for deduplication.
+/// Vectors with a `NaN` komponent are unordered; see [`Vector2::total_cmp`] for an ordering that includes them.
+/// # Examples
+/// ```
+/// use cute_gorl::vector2::*;
+/// assert!(Vector2 { x: 1., y: 5. } < Vector2 { x: 2., y: 0. });
+/// assert!(Vector2 { x: 1., y: 5. } > Vector2 { x: 1., y: 2. });
+/// ```
+impl<T: Scalar> PartialOrd for Vector2<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.x.partial_cmp(&other.x)? {
+            Ordering::Equal => self.y.partial_cmp(&other.y),
+            ord => Some(ord)
+        }
+    }
+}
+impl<T: Scalar> Add for &Vector2<T>{
+    type Output = Vector2<T>;
+    fn add(self, other: &Vector2<T>) -> Self::Output {
+        Vector2 { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+impl<T: Scalar> Sub for &Vector2<T>{
+    type Output = Vector2<T>;
+    fn sub(self, other: &Vector2<T>) -> Self::Output {
         Vector2 { x: self.x-other.x, y: self.y-other.y }
     }
 }
-impl Mul<f64> for &Vector2{
-    type Output = Vector2;
-    fn mul(self, factor: f64) -> Self::Output {
+impl<T: Scalar> Mul<T> for &Vector2<T>{
+    type Output = Vector2<T>;
+    fn mul(self, factor: T) -> Self::Output {
         Vector2 { x: self.x*factor, y: self.y*factor }
     }
 }
-impl Mul<&Vector2> for f64{
-    type Output = Vector2;
-    fn mul(self, vector: &Vector2) -> Self::Output {
-        Vector2 { x: vector.x*self, y: vector.y*self }
+// `impl<T: Scalar> Mul<Vector2<T>> for T` is ruled out by the orphan rule, so these are spelled out per scalar
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {$(
+        impl Mul<&Vector2<$t>> for $t{
+            type Output = Vector2<$t>;
+            fn mul(self, vector: &Vector2<$t>) -> Self::Output {
+                Vector2 { x: vector.x*self, y: vector.y*self }
+            }
+        }
+        impl Mul<Vector2<$t>> for $t{
+            type Output = Vector2<$t>;
+            fn mul(self, vector: Vector2<$t>) -> Self::Output {
+                Vector2 { x: vector.x*self, y: vector.y*self }
+            }
+        }
+    )*};
+}
+impl_scalar_mul!(f32, f64);
+impl<T: Scalar> Div<T> for &Vector2<T>{
+    type Output = Vector2<T>;
+    fn div(self, quotient: T) -> Self::Output {
+        let f: T = T::ONE/quotient;
+        Vector2 { x: self.x*f, y: self.y*f }
+    }
+}
+impl<T: Scalar> Add for Vector2<T>{
+    type Output = Vector2<T>;
+    fn add(self, other: Vector2<T>) -> Self::Output {
+        Vector2 { x: self.x + other.x, y: self.y + other.y }
     }
 }
-impl Div<f64> for &Vector2{
-    type Output = Vector2;
-    fn div(self, quotient: f64) -> Self::Output {
-        let f: f64 = 1./quotient;
+impl<T: Scalar> Add<&Vector2<T>> for Vector2<T>{
+    type Output = Vector2<T>;
+    fn add(self, other: &Vector2<T>) -> Self::Output {
+        Vector2 { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+impl<T: Scalar> Add<Vector2<T>> for &Vector2<T>{
+    type Output = Vector2<T>;
+    fn add(self, other: Vector2<T>) -> Self::Output {
+        Vector2 { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+impl<T: Scalar> Sub for Vector2<T>{
+    type Output = Vector2<T>;
+    fn sub(self, other: Vector2<T>) -> Self::Output {
+        Vector2 { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+impl<T: Scalar> Sub<&Vector2<T>> for Vector2<T>{
+    type Output = Vector2<T>;
+    fn sub(self, other: &Vector2<T>) -> Self::Output {
+        Vector2 { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+impl<T: Scalar> Sub<Vector2<T>> for &Vector2<T>{
+    type Output = Vector2<T>;
+    fn sub(self, other: Vector2<T>) -> Self::Output {
+        Vector2 { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+impl<T: Scalar> Mul<T> for Vector2<T>{
+    type Output = Vector2<T>;
+    fn mul(self, factor: T) -> Self::Output {
+        Vector2 { x: self.x*factor, y: self.y*factor }
+    }
+}
+impl<T: Scalar> Div<T> for Vector2<T>{
+    type Output = Vector2<T>;
+    fn div(self, quotient: T) -> Self::Output {
+        let f: T = T::ONE/quotient;
         Vector2 { x: self.x*f, y: self.y*f }
     }
 }
-impl AddAssign<Self> for Vector2 {
+impl<T: Scalar> AddAssign<Self> for Vector2<T> {
     fn add_assign(&mut self, other: Self) {
         self.x += other.x;
         self.y += other.y;
     }
 }
-impl AddAssign<&Self> for Vector2 {
+impl<T: Scalar> AddAssign<&Self> for Vector2<T> {
     fn add_assign(&mut self, other: &Self) {
         self.x += other.x;
         self.y += other.y;
     }
 }
-impl SubAssign<Self> for Vector2 {
+impl<T: Scalar> SubAssign<Self> for Vector2<T> {
     fn sub_assign(&mut self, other: Self) {
         self.x -= other.x;
         self.y -= other.y;
     }
 }
-impl SubAssign<&Self> for Vector2 {
+impl<T: Scalar> SubAssign<&Self> for Vector2<T> {
     fn sub_assign(&mut self, other: &Self) {
         self.x -= other.x;
         self.y -= other.y;
     }
 }
-impl MulAssign<f64> for Vector2 {
-    fn mul_assign(&mut self, other: f64) {
+impl<T: Scalar> MulAssign<T> for Vector2<T> {
+    fn mul_assign(&mut self, other: T) {
         self.x *= other;
         self.y *= other;
     }
 }
-impl DivAssign<f64> for Vector2 {
-    fn div_assign(&mut self, other: f64) {
-        let f: f64 = 1./other;
+impl<T: Scalar> DivAssign<T> for Vector2<T> {
+    fn div_assign(&mut self, other: T) {
+        let f: T = T::ONE/other;
         self.x *= f;
         self.y *= f;
     }
-}
\ No newline at end of file
+}
+impl<T: Scalar> Sum for Vector2<T> {
+    fn sum<I: Iterator<Item = Vector2<T>>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, v| acc + v)
+    }
+}
+impl<'a, T: Scalar> Sum<&'a Vector2<T>> for Vector2<T> {
+    fn sum<I: Iterator<Item = &'a Vector2<T>>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, v| acc + v)
+    }
+}
+
+impl<T: Scalar> IntoIterator for Vector2<T> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, 2>;
+    /// Iterates over the components of a [`Vector2`] in order.
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y].into_iter()
+    }
+}
+/// Reads a [`Vector2`] from a slice of exactly 2 komponents, failing for any other length.
+/// # Examples
+/// ```
+/// use cute_gorl::vector2::*;
+/// let flat: Vec<f64> = vec![0., 1., 2., 4.];
+/// assert_eq!(Vector2::try_from(&flat[1..3]).ok(), Some(Vector2 { x: 1., y: 2. }));
+/// assert!(Vector2::try_from(&flat[..]).is_err());
+/// ```
+impl<T: Scalar> TryFrom<&[T]> for Vector2<T> {
+    type Error = TryFromSliceError;
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        let [x, y] = <[T; 2]>::try_from(slice)?;
+        Ok(Vector2 { x, y })
+    }
+}
+impl<T: Scalar> AsRef<[T; 2]> for Vector2<T> {
+    fn as_ref(&self) -> &[T; 2] {
+        // SAFETY: Vector2 is #[repr(C)] with two f64 fields, so it has the layout of [f64; 2].
+        unsafe { &*(self as *const Self as *const [T; 2]) }
+    }
+}
+
+/// Calculates the centroid (the average) of a number of [`Vector2`]s interpreted as points, or `None` if there are none.
+/// # Examples
+/// ```
+/// use cute_gorl::vector2::*;
+/// let points = [
+///     Vector2 { x: 0., y: 0. },
+///     Vector2 { x: 4., y: 0. },
+///     Vector2 { x: 2., y: 3. }
+/// ];
+/// assert_eq!(centroid(&points), Some(Vector2 { x: 2., y: 1. }));
+/// assert_eq!(centroid(&[]), None);
+/// ```
+pub fn centroid(points: &[Vector2]) -> Option<Vector2> {
+    if points.is_empty() { return None; }
+    Some(points.iter().sum::<Vector2>() / points.len() as f64)
+}
+
+/// Calculates the weighted average of a number of [`Vector2`]s interpreted as points, where `weights[i]` belongs to `points[i]`.
+/// `None` if the lengths of `points` and `weights` differ or the weights add up to `0.0`.
+/// # Examples
+/// ```
+/// use cute_gorl::vector2::*;
+/// let points = [Vector2 { x: 0., y: 0. }, Vector2 { x: 4., y: 8. }];
+/// assert_eq!(weighted_average(&points, &[3., 1.]), Some(Vector2 { x: 1., y: 2. }));
+/// assert_eq!(weighted_average(&points, &[1.]), None);
+/// assert_eq!(weighted_average(&points, &[1., -1.]), None);
+/// ```
+pub fn weighted_average(points: &[Vector2], weights: &[f64]) -> Option<Vector2> {
+    if points.len() != weights.len() { return None; }
+    let total: f64 = weights.iter().sum();
+    if total == 0.0 { return None; }
+    let weighted: Vector2 = points.iter().zip(weights).map(|(p, w)| p * *w).sum();
+    Some(weighted / total)
+}
@@ -1,24 +1,93 @@
 //! Mathematical vectors in 3 dimentional space. 
 use std::ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign, Neg};
-use std::cmp::PartialEq;
-use crate::{math, vector2::Vector2};
+use std::cmp::{PartialEq, PartialOrd, Ordering};
+use std::fmt;
+use std::iter::Sum;
+use std::hash::{Hash, Hasher};
+use std::array::TryFromSliceError;
+use crate::{math, scalar::Scalar, vector2::Vector2};
 
 ///3D Vector
-#[derive(Copy, Clone, Debug, Default)]
-pub struct Vector3 {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64
+///
+///Generic over its komponent type, which is `f64` unless stated otherwise, see [`Scalar`].
+///Laid out like `[T; 3]`, i.e. the components are stored in order without padding.
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct Vector3<T: Scalar = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T
 }
 
+/// A [`Vector3`] of `f64`s, the same as a plain `Vector3`.
+pub type Vector3f64 = Vector3<f64>;
+/// A [`Vector3`] of `f32`s, half the size of a [`Vector3f64`].
+pub type Vector3f32 = Vector3<f32>;
+
 /// The Nullvector (0 0 0)
 pub const NULL: Vector3 = Vector3 { x: 0., y: 0., z:0. };
 
-impl Vector3 {
+/// A [`Vector3`] that can be used as a key in a `HashMap` or `HashSet`.
+/// Components are compared by their bit patterns rather than by `==`, so `-0.0` and `0.0` are different keys
+/// while a `NaN` equals itself. This makes most sense for vectors with integer components, e.g. after [`Vector3::round`].
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// use std::collections::HashSet;
+/// let mut set = HashSet::new();
+/// set.insert(HashableVector3(Vector3 { x: 1., y: 2., z: 3. }));
+/// assert!(set.contains(&HashableVector3(Vector3 { x: 1., y: 2., z: 3. })));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct HashableVector3(pub Vector3);
+
+impl HashableVector3 {
+    #[inline]
+    fn bits(&self) -> (u64, u64, u64) {
+        (self.0.x.to_bits(), self.0.y.to_bits(), self.0.z.to_bits())
+    }
+}
+impl PartialEq for HashableVector3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+impl Eq for HashableVector3 {}
+impl Hash for HashableVector3 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+impl From<Vector3> for HashableVector3 {
+    #[inline]
+    fn from(v: Vector3) -> Self {
+        Self(v)
+    }
+}
+
+impl<T: Scalar> Vector3<T> {
+    /// The null-vector (0 0 0), the same as [`NULL`].
+    pub const ZERO: Self = Self { x: T::ZERO, y: T::ZERO, z: T::ZERO };
+    /// The vector (1 1 1).
+    pub const ONE: Self = Self { x: T::ONE, y: T::ONE, z: T::ONE };
+    /// The unit vector along the x-axis (1 0 0).
+    pub const UNIT_X: Self = Self { x: T::ONE, y: T::ZERO, z: T::ZERO };
+    /// The unit vector along the y-axis (0 1 0).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let up = Vector3::UNIT_Y;
+    /// let velocity = Vector3 { x: 2., y: 0., z: -1. } + up * 5.;
+    /// assert_eq!(velocity, Vector3 { x: 2., y: 5., z: -1. });
+    /// assert!(up.is_normalized());
+    /// ```
+    pub const UNIT_Y: Self = Self { x: T::ZERO, y: T::ONE, z: T::ZERO };
+    /// The unit vector along the z-axis (0 0 1).
+    pub const UNIT_Z: Self = Self { x: T::ZERO, y: T::ZERO, z: T::ONE };
 
     #[inline(always)]
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
-        Vector3 { x: x, y: y, z: z}
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vector3 { x, y, z }
     }
 
     /// Tetermines whether or not a [`Vector3`]'s komponents are all equal to `0.0`.
@@ -32,9 +101,22 @@ impl Vector3 {
     /// ```
     #[inline]
     pub fn is_nullvector(&self) -> bool { 
-           self.x == 0.
-        && self.y == 0.
-        && self.z == 0.
+        self.is_nullvector_eps(T::ZERO)
+    }
+
+    /// Determines whether or not a [`Vector3`]'s components all lie within `eps` of `0.0`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v: Vector3 = Vector3 { x: 1e-6, y: -1e-6, z: 0. };
+    /// assert!(v.is_nullvector_eps(1e-5));
+    /// assert!( !(v.is_nullvector_eps(cute_gorl::EPSILON)) );
+    /// ```
+    #[inline]
+    pub fn is_nullvector_eps(&self, eps: T) -> bool {
+           self.x.abs() <= eps
+        && self.y.abs() <= eps
+        && self.z.abs() <= eps
     }
 
     /// Tetermines whether or not a [`Vector3`] is normalized (of length `1`).
@@ -48,11 +130,56 @@ impl Vector3 {
     /// ```
     pub fn is_normalized(&self) -> bool {
         // allows for a slim margin of error to account for inaccuracy of floating-point math
-        let diff = 1.0 - self.magn_sq();
-        diff.abs() < math::EPSILON
+        self.is_normalized_eps(T::EPSILON)
+    }
+
+    /// Determines whether or not a [`Vector3`] is normalized, using a caller-supplied tolerance `eps` instead of the default.
+    /// The difference is measured on the square of the magnitude, just like in [`Vector3::is_normalized`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v: Vector3 = Vector3 { x: 0., y: 1.00001, z: 0. };
+    /// assert!(v.is_normalized_eps(1e-4));
+    /// assert!( !(v.is_normalized()) );
+    /// ```
+    pub fn is_normalized_eps(&self, eps: T) -> bool {
+        let diff = T::ONE - self.magn_sq();
+        diff.abs() < eps
+    }
+
+    /// Determines whether or not two [`Vector3`]s are equal, allowing each component to differ by up to `epsilon`.
+    /// Prefer this over `==` for vectors that went through floating-point arithmetic.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v1 = Vector3 { x: 1., y: 2., z: 3. };
+    /// let v2 = Vector3 { x: 1., y: 2. + 1e-10, z: 3. };
+    /// assert!(v1.approx_eq(&v2, 1e-9));
+    /// assert!( !(v1.approx_eq(&Vector3 { x: 1., y: 2., z: 3.001 }, 1e-9)) );
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        (self.x - other.x).abs() <= epsilon &&
+        (self.y - other.y).abs() <= epsilon &&
+        (self.z - other.z).abs() <= epsilon
+    }
+
+    /// Determines whether or not two [`Vector3`]s are equal within the same tolerance (`1e-8`) that [`Vector3::is_normalized`] uses.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v1 = Vector3 { x: 1., y: 2., z: 3. };
+    /// let v2 = Vector3 { x: 1., y: 2. + 1e-10, z: 3. };
+    /// assert_ne!(v1, v2);
+    /// assert!(v1.approx_eq_default(&v2));
+    /// ```
+    #[inline]
+    pub fn approx_eq_default(&self, other: &Self) -> bool {
+        self.approx_eq(other, T::EPSILON)
     }
 
     /// Tetermines whether or not one [`Vector3`] is a multiple of the other. The inputs must not be null-vectors.
+    /// The crossproduct has to be exactly zero, see [`Vector3::is_collinear_eps`] for a tolerant version.
     /// # Examples
     /// ```
     /// use cute_gorl::vector3::*;
@@ -64,9 +191,58 @@ impl Vector3 {
     /// ```
     pub fn is_collinear(v1:&Self, v2:&Self) -> bool {
         // checks if the crossproduct is the nullvector
-        v1.y*v2.z - v1.z*v2.y == 0.0 &&
-        v1.z*v2.x - v1.x*v2.z == 0.0 &&
-        v1.x*v2.y - v1.y*v2.x == 0.0
+        v1.y*v2.z - v1.z*v2.y == T::ZERO &&
+        v1.z*v2.x - v1.x*v2.z == T::ZERO &&
+        v1.x*v2.y - v1.y*v2.x == T::ZERO
+    }
+
+    /// Tolerant version of [`Vector3::is_collinear`], which only holds exactly and so rarely for computed vectors.
+    /// The test is relative: the magnitude of the crossproduct may be at most `eps` times the product of the magnitudes,
+    /// i.e. the sine of the angle between the vectors may be at most `eps`. `1e-8` ([`crate::EPSILON`]) is a good default.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v1: Vector3 = Vector3 { x: 0.1, y: 0.7, z: 0.3 };
+    /// let v2: Vector3 = &(&v1 / 3.) * 7.;
+    /// assert!( !(Vector3::is_collinear(&v1, &v2)) );
+    /// assert!( Vector3::is_collinear_eps(&v1, &v2, cute_gorl::EPSILON) );
+    /// ```
+    pub fn is_collinear_eps(v1: &Self, v2: &Self, eps: T) -> bool {
+        // compares the magnitude of the crossproduct to the product of the magnitudes
+        Self::crossp(v1, v2).magn_sq() <= eps*eps * v1.magn_sq() * v2.magn_sq()
+    }
+
+    /// Determines whether or not two [`Vector3`]s point along the same line, in the same or in opposite directions.
+    /// This is the same relative test as [`Vector3::is_collinear_eps`]: the sine of the angle between them may be at most `eps`.
+    /// Unlike there, the null-vector has no direction and so is never parallel to anything.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 3., y: 1., z: 2. };
+    /// assert!( Vector3::is_parallel(&v, &Vector3 { x: -6., y: -2., z: -4. }, 1e-8) );
+    /// assert!( !(Vector3::is_parallel(&v, &Vector3 { x: 3., y: 1., z: 2.001 }, 1e-8)) );
+    /// assert!( !(Vector3::is_parallel(&v, &Vector3 { x: 0., y: 0., z: 0. }, 1e-8)) );
+    /// ```
+    pub fn is_parallel(v1: &Self, v2: &Self, eps: T) -> bool {
+        !v1.is_nullvector() && !v2.is_nullvector() && Self::is_collinear_eps(v1, v2, eps)
+    }
+
+    /// Determines whether or not two [`Vector3`]s are at a right angle to each other.
+    /// The test is relative like in [`Vector3::is_collinear_eps`]: the cosine of the angle between them may be at most `eps`.
+    /// The null-vector has no direction and so is never perpendicular to anything.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 3., y: 1., z: 2. };
+    /// assert!( Vector3::is_perpendicular(&v, &Vector3 { x: -2., y: 6., z: 0. }, 1e-8) );
+    /// assert!( !(Vector3::is_perpendicular(&v, &Vector3 { x: -2., y: 6., z: 0.001 }, 1e-8)) );
+    /// assert!( !(Vector3::is_perpendicular(&Vector3 { x: 0., y: 0., z: 0. }, &v, 1e-8)) );
+    /// ```
+    pub fn is_perpendicular(v1: &Self, v2: &Self, eps: T) -> bool {
+        if v1.is_nullvector() || v2.is_nullvector() { return false; }
+        // compares the dotproduct to the product of the magnitudes
+        let dot: T = Self::scalar(v1, v2);
+        dot*dot <= eps*eps * v1.magn_sq() * v2.magn_sq()
     }
 
     /// Tetermines whether or not there exists a plane that contains all three vectors. The inputs must not be null-vectors.
@@ -81,10 +257,25 @@ impl Vector3 {
     /// assert!( !(Vector3::is_coplanar(&v1, &v2, &v3)) );
     /// ```
     pub fn is_coplanar(v1: &Self, v2: &Self, v3: &Self) -> bool {
-        // checks if scalar product of v3 and (crossproduct of v1 and v2) is 0
-        (v1.y*v2.z - v1.z*v2.y)*v3.x +
-        (v1.z*v2.x - v1.x*v2.z)*v3.y +
-        (v1.x*v2.y - v1.y*v2.x)*v3.z == 0.0
+        Self::triple_product(v1, v2, v3) == T::ZERO
+    }
+
+    /// Calculates the scalar triple product `a * (b x c)` of three [`Vector3`]s, which is the signed volume
+    /// of the parallelepiped they span. It is positive if `a`, `b` and `c` form a right-handed system
+    /// and `0.0` if they are coplanar.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let a = Vector3 { x: 1., y: 0., z: 0. };
+    /// let b = Vector3 { x: 0., y: 2., z: 0. };
+    /// let c = Vector3 { x: 1., y: 1., z: 3. };
+    /// assert_eq!(Vector3::triple_product(&a, &b, &c), 6.);
+    /// assert_eq!(Vector3::triple_product(&a, &c, &b), -6.);
+    /// assert_eq!(Vector3::triple_product(&a, &b, &(a + b)), 0.);
+    /// ```
+    #[inline]
+    pub fn triple_product(a: &Self, b: &Self, c: &Self) -> T {
+        Self::scalar(a, &Self::crossp(b, c))
     }
 
     /// Calculates the scalar/dot-product of two [`Vector3`]s.
@@ -97,10 +288,23 @@ impl Vector3 {
     /// assert_eq!( scalar, -27. );
     /// ```
     #[inline]
-    pub fn scalar(v1: &Self, v2: &Self) -> f64 {
+    pub fn scalar(v1: &Self, v2: &Self) -> T {
         v1.x*v2.x + v1.y*v2.y + v1.z*v2.z
     }
 
+    /// Method form of [`Vector3::scalar`], the scalar/dot-product of two [`Vector3`]s.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let a = Vector3 { x: 1.5, y: -2., z: 1. };
+    /// let b = Vector3 { x: 2., y: 0.5, z: 4. };
+    /// assert_eq!(a.dot(&b), 6.);
+    /// ```
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T {
+        Self::scalar(self, other)
+    }
+
     /// Scales a [`Vector3`] to a magnitude of 1.
     /// # Examples
     /// ```
@@ -111,13 +315,56 @@ impl Vector3 {
     /// v2.normalize();
     /// assert!(v1.is_normalized() && v2.is_normalized());
     /// ```
-    pub fn normalize(&mut self) -> () {
-        let inv_magn = 1. / self.magn();
+    pub fn normalize(&mut self) {
+        let inv_magn = T::ONE / self.magn();
         self.x *= inv_magn;
         self.y *= inv_magn;
         self.z *= inv_magn;
     }
 
+    /// Returns a normalized copy of a [`Vector3`] together with its original magnitude, leaving it unchanged.
+    /// The null-vector has no direction and yields `(NULL, 0.0)`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 2., y: 3., z: 6. };
+    /// let (n, len) = v.normalized_and_length();
+    /// assert!(n.is_normalized());
+    /// assert_eq!(len, 7.);
+    /// assert_eq!(NULL.normalized_and_length(), (NULL, 0.0));
+    /// ```
+    pub fn normalized_and_length(&self) -> (Self, T) {
+        let len: T = self.magn();
+        if len == T::ZERO { return (Self::ZERO, T::ZERO); }
+        (self * (T::ONE / len), len)
+    }
+
+    /// Returns a normalized copy of a [`Vector3`], or the null-vector if it has a length of `0.0`
+    /// (where [`Vector3::normalize`] would produce `NaN`s).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// assert_eq!(Vector3 { x: 0., y: -3., z: 0. }.normalize_or_zero(), Vector3 { x: 0., y: -1., z: 0. });
+    /// assert_eq!(NULL.normalize_or_zero(), NULL);
+    /// ```
+    #[inline]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalized_and_length().0
+    }
+
+    /// Returns a normalized copy of a [`Vector3`], or `fallback` if it has a length of `0.0`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let forward = Vector3 { x: 1., y: 0., z: 0. };
+    /// assert_eq!(Vector3 { x: 0., y: -3., z: 0. }.normalize_or(forward), Vector3 { x: 0., y: -1., z: 0. });
+    /// assert_eq!(NULL.normalize_or(forward), forward);
+    /// ```
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        let (dir, len) = self.normalized_and_length();
+        if len == T::ZERO { fallback } else { dir }
+    }
+
     /// Calculates the square of the magnitude of a [`Vector3`].
     /// # Examples
     /// ```
@@ -126,7 +373,7 @@ impl Vector3 {
     /// assert_eq!(v1.magn_sq(), 38.5_f64 );
     /// ```
     #[inline]
-    pub fn magn_sq(&self) -> f64 {
+    pub fn magn_sq(&self) -> T {
         self.x*self.x + self.y*self.y + self.z*self.z
     }
 
@@ -138,10 +385,69 @@ impl Vector3 {
     /// assert_eq!(v1.magn(), 38.5_f64.sqrt() );
     /// ```
     #[inline]
-    pub fn magn(&self) -> f64 {
+    pub fn magn(&self) -> T {
         self.magn_sq().sqrt()
     }
 
+    /// Calculates the magnitude of a [`Vector3`] while avoiding the intermediate overflow (or underflow)
+    /// of squaring huge (or tiny) komponents that makes [`Vector3::magn`] return infinity (or `0.0`).
+    /// The komponents are scaled down by the largest one before squaring, which costs a few divisions,
+    /// so only use it where komponents can get that large.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 2e200, y: 3e200, z: 6e200 };
+    /// assert_eq!(v.magn(), f64::INFINITY);
+    /// assert!((v.magn_stable() / 7e200 - 1.).abs() < 1e-15);
+    /// ```
+    pub fn magn_stable(&self) -> T {
+        let max: T = self.x.abs().max(self.y.abs()).max(self.z.abs());
+        // nothing to scale for the null-vector, and infinite or NaN komponents decide the result anyway
+        if max == T::ZERO || !max.is_finite() { return self.magn(); }
+        let scaled: Self = self * (T::ONE / max);
+        max * scaled.magn()
+    }
+
+    /// Alias of [`Vector3::magn`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 3., y: 0., z: 4. };
+    /// assert_eq!(v.length(), 5.);
+    /// assert_eq!(v.magnitude(), v.length());
+    /// assert_eq!(v.length_squared(), 25.);
+    /// ```
+    #[inline(always)]
+    pub fn length(&self) -> T {
+        self.magn()
+    }
+
+    /// Alias of [`Vector3::magn_sq`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 2., y: 3., z: 6. };
+    /// assert_eq!(v.length_squared(), 49.);
+    /// assert_eq!(v.length_squared(), v.magn_sq());
+    /// ```
+    #[inline(always)]
+    pub fn length_squared(&self) -> T {
+        self.magn_sq()
+    }
+
+    /// Alias of [`Vector3::magn`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 2., y: 3., z: 6. };
+    /// assert_eq!(v.magnitude(), 7.);
+    /// assert_eq!(v.magnitude(), v.magn());
+    /// ```
+    #[inline(always)]
+    pub fn magnitude(&self) -> T {
+        self.magn()
+    }
+
     /// Calculates the the square of distance between two [`Vector3`]s interpreted as points.
     /// # Examples
     /// ```
@@ -151,10 +457,10 @@ impl Vector3 {
     /// assert_eq!(Vector3::dist_sq(&v1, &v2), 38.5_f64 );
     /// ```
     #[inline]
-    pub fn dist_sq(v1: &Self, v2: &Self) -> f64 {
-        let dx: f64 = v1.x-v2.x;
-        let dy: f64 = v1.y-v2.y;
-        let dz: f64 = v1.z-v2.z;
+    pub fn dist_sq(v1: &Self, v2: &Self) -> T {
+        let dx: T = v1.x-v2.x;
+        let dy: T = v1.y-v2.y;
+        let dz: T = v1.z-v2.z;
         dx*dx + dy*dy + dz*dz
     }
     
@@ -167,10 +473,64 @@ impl Vector3 {
     /// assert_eq!(Vector3::dist(&v1, &v2), 38.5_f64.sqrt() );
     /// ```
     #[inline]
-    pub fn dist(v1: &Self, v2: &Self) -> f64 {
+    pub fn dist(v1: &Self, v2: &Self) -> T {
         Self::dist_sq(v1, v2).sqrt()
     }
 
+    /// Method form of [`Vector3::dist`], the distance between two [`Vector3`]s interpreted as points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let a = Vector3 { x: 1., y: 1., z: 2. };
+    /// let b = Vector3 { x: 4., y: 5., z: 2. };
+    /// assert_eq!(a.distance(&b), 5.);
+    /// ```
+    #[inline]
+    pub fn distance(&self, other: &Self) -> T {
+        Self::dist(self, other)
+    }
+
+    /// Method form of [`Vector3::dist_sq`], the square of the distance between two [`Vector3`]s interpreted as points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let a = Vector3 { x: 1., y: 1., z: 2. };
+    /// let b = Vector3 { x: 4., y: 5., z: 2. };
+    /// assert_eq!(a.distance_sq(&b), 25.);
+    /// ```
+    #[inline]
+    pub fn distance_sq(&self, other: &Self) -> T {
+        Self::dist_sq(self, other)
+    }
+
+    /// Calculates the manhattan (taxicab) distance `|dx| + |dy| + |dz|` between two [`Vector3`]s interpreted as points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v1 = Vector3 { x: 0., y: 0., z: 0. };
+    /// let v2 = Vector3 { x: 2., y: 3., z: 6. };
+    /// assert_eq!(Vector3::dist(&v1, &v2), 7.);
+    /// assert_eq!(Vector3::dist_manhattan(&v1, &v2), 11.);
+    /// assert_eq!(Vector3::dist_chebyshev(&v1, &v2), 6.);
+    /// ```
+    pub fn dist_manhattan(v1: &Self, v2: &Self) -> T {
+        (v1.x - v2.x).abs() + (v1.y - v2.y).abs() + (v1.z - v2.z).abs()
+    }
+
+    /// Calculates the chebyshev distance `max(|dx|, |dy|, |dz|)` between two [`Vector3`]s interpreted as points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v1 = Vector3 { x: 0., y: 0., z: 0. };
+    /// let v2 = Vector3 { x: 2., y: 2., z: 2. };
+    /// assert_eq!(Vector3::dist_chebyshev(&v1, &v2), 2.);
+    /// assert_eq!(Vector3::dist_manhattan(&v1, &v2), 6.);
+    /// assert!((Vector3::dist(&v1, &v2) - 2. * 3_f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn dist_chebyshev(v1: &Self, v2: &Self) -> T {
+        (v1.x - v2.x).abs().max((v1.y - v2.y).abs()).max((v1.z - v2.z).abs())
+    }
+
     /// Scales down a [`Vector3`] to a magnitude if it exceeds that magnitude.
     /// # Examples
     /// ```
@@ -179,9 +539,10 @@ impl Vector3 {
     /// v.clamp_max(1_f64);
     /// assert!(v.is_normalized());
     /// ```
-    pub fn clamp_max(&mut self, max: f64) {
-        let len: f64 = self.magn();
-        if len > max { *self *= max / len; }
+    pub fn clamp_max(&mut self, max: T) {
+        // compares squares first so that the common in-range case needs no sqrt
+        let len_sq: T = self.magn_sq();
+        if max < T::ZERO || len_sq > max*max { *self *= max / len_sq.sqrt(); }
     }
 
     /// Scales up a [`Vector3`] to a magnitude if it is shorter than that magnitude.
@@ -192,12 +553,76 @@ impl Vector3 {
     /// v.clamp_max(1_f64);
     /// assert!(v.is_normalized());
     /// ```
-    pub fn clamp_min(&mut self, min: f64) {
-        let len: f64 = self.magn();
-        if len < min { *self *= min / len; }
+    pub fn clamp_min(&mut self, min: T) {
+        let len_sq: T = self.magn_sq();
+        if min > T::ZERO && len_sq < min*min { *self *= min / len_sq.sqrt(); }
+    }
+
+    /// Returns a copy of a [`Vector3`] with its magnitude clamped into `[min, max]`, keeping its direction.
+    /// If `min > max` the vector is clamped to `max`. The null-vector has no direction and is returned unchanged.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let short = Vector3 { x: 0., y: 0., z: 1. };
+    /// let fine = Vector3 { x: 1., y: 2., z: 2. };
+    /// let long = Vector3 { x: 4., y: 8., z: 8. };
+    /// assert_eq!(short.clamp_length(2., 6.), Vector3 { x: 0., y: 0., z: 2. });
+    /// assert_eq!(fine.clamp_length(2., 6.), fine);
+    /// assert_eq!(long.clamp_length(2., 6.), Vector3 { x: 2., y: 4., z: 4. });
+    /// assert_eq!(long.clamp_length(8., 6.), Vector3 { x: 2., y: 4., z: 4. });
+    /// assert_eq!(NULL.clamp_length(2., 6.), NULL);
+    /// ```
+    pub fn clamp_length(&self, min: T, max: T) -> Self {
+        let len: T = self.magn();
+        if len == T::ZERO { return *self; }
+        let clamped: T = len.max(min).min(max);
+        self * (clamped / len)
+    }
+
+    /// Like [`Vector3::clamp_length`], but also returns the normalized direction of the original [`Vector3`].
+    /// The direction of the null-vector is the null-vector.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 3., y: 0., z: 0. };
+    /// let (clamped, dir) = v.clamp_length_with_direction(0., 1.);
+    /// assert_eq!(clamped, Vector3 { x: 1., y: 0., z: 0. });
+    /// assert_eq!(dir, clamped);
+    /// ```
+    pub fn clamp_length_with_direction(&self, min: T, max: T) -> (Self, Self) {
+        let (dir, len) = self.normalized_and_length();
+        if len < min || len > max { (&dir * len.max(min).min(max), dir) }
+        else { (*self, dir) }
+    }
+
+    /// Returns a [`Vector3`] pointing in the same direction with a magnitude of `len`.
+    /// The null-vector has no direction and stays the null-vector.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 3., y: 0., z: 4. };
+    /// assert!(v.with_magnitude(10.).approx_eq(&Vector3 { x: 6., y: 0., z: 8. }, 1e-12));
+    /// assert_eq!(NULL.with_magnitude(10.), NULL);
+    /// ```
+    pub fn with_magnitude(&self, len: T) -> Self {
+        let (dir, _) = self.normalized_and_length();
+        dir * len
+    }
+
+    /// Sets the magnitude of a [`Vector3`] to `len`, keeping its direction. See [`Vector3::with_magnitude`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let mut v = Vector3 { x: 0., y: -2., z: 0. };
+    /// v.set_magnitude(5.);
+    /// assert_eq!(v, Vector3 { x: 0., y: -5., z: 0. });
+    /// ```
+    pub fn set_magnitude(&mut self, len: T) {
+        *self = self.with_magnitude(len);
     }
 
     /// Linearly interpolate between two [`Vector3`]s interpreted as points.
+    /// `factor` is not clamped, so values outside of `[0, 1]` extrapolate beyond `v1` or `v2`; see [`Vector3::lerp_clamped`].
     /// # Examples
     /// ```
     /// use cute_gorl::vector3::*;
@@ -205,8 +630,8 @@ impl Vector3 {
     /// let v2 = -v1;
     /// assert_eq!(Vector3::lerp(&v1, &v2, 0.5_f64), NULL);
     /// ```
-    pub fn lerp(v1: &Self, v2: &Self, factor: f64) -> Self {
-        let temp = 1.0 - factor;
+    pub fn lerp(v1: &Self, v2: &Self, factor: T) -> Self {
+        let temp = T::ONE - factor;
         Vector3 {
             x: v1.x*temp + v2.x*factor,
             y: v1.y*temp + v2.y*factor,
@@ -214,20 +639,125 @@ impl Vector3 {
         }
     }
 
+    /// The point halfway between two [`Vector3`]s interpreted as points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let a = Vector3 { x: 1., y: -2., z: 0. };
+    /// let b = Vector3 { x: 3., y: 4., z: 5. };
+    /// assert_eq!(Vector3::midpoint(&a, &b), Vector3 { x: 2., y: 1., z: 2.5 });
+    /// ```
+    #[inline]
+    pub fn midpoint(a: &Self, b: &Self) -> Self {
+        Self::lerp(a, b, T::HALF)
+    }
+
+    /// Interpolates between two directions by normalizing the result of [`Vector3::lerp`], so for unit inputs the result is a unit vector.
+    /// Unlike a spherical interpolation it does not move at a constant angular velocity (it is faster in the middle),
+    /// but it is much cheaper and good enough to e.g. smoothly turn towards a direction.
+    /// Directions exactly opposite of each other have no interpolation at `t = 0.5` and give the null-vector there.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let a = Vector3 { x: 1., y: 0., z: 0. };
+    /// let b = Vector3 { x: 0., y: 1., z: 0. };
+    /// let halfway = Vector3f64::nlerp(&a, &b, 0.5);
+    /// assert!(halfway.is_normalized());
+    /// assert!((halfway.x - halfway.y).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn nlerp(a: &Self, b: &Self, t: T) -> Self {
+        Self::lerp(a, b, t).normalize_or_zero()
+    }
+
+    /// Moves a [`Vector3`] interpreted as a point in place, linearly interpolating it towards `target` by `t`.
+    /// Same as `*self = Vector3::lerp(self, target, t)`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let mut pos = Vector3 { x: 0., y: 2., z: -4. };
+    /// pos.lerp_towards(&Vector3 { x: 4., y: 0., z: 4. }, 0.25);
+    /// assert_eq!(pos, Vector3 { x: 1., y: 1.5, z: -2. });
+    /// ```
+    #[inline]
+    pub fn lerp_towards(&mut self, target: &Self, t: T) {
+        *self = Self::lerp(self, target, t);
+    }
+
+    /// Linearly interpolate between two [`Vector3`]s interpreted as points, clamping `factor` into `[0, 1]` first,
+    /// so the result always lies on the segment between `v1` and `v2`. Use [`Vector3::lerp`] to extrapolate.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1., y: 2., z: 3. };
+    /// assert_eq!(Vector3::lerp_clamped(&NULL, &v, 2.), v);
+    /// assert_eq!(Vector3::lerp_clamped(&NULL, &v, -1.), NULL);
+    /// ```
+    pub fn lerp_clamped(v1: &Self, v2: &Self, factor: T) -> Self {
+        Self::lerp(v1, v2, factor.clamp(T::ZERO, T::ONE))
+    }
+
+    /// Linearly interpolate between two [`Vector3`]s interpreted as points in a wrap-around world.
+    /// Each axis spans the half-open range `[domain_min, domain_max)` and leaving it on one side
+    /// re-enters on the other, so every component moves along whichever direction is shorter.
+    /// The result is wrapped back into the domain.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let min = Vector3 { x: 0., y: 0., z: 0. };
+    /// let max = Vector3 { x: 10., y: 10., z: 10. };
+    /// let v1 = Vector3 { x: 9., y: 2., z: 5. };
+    /// let v2 = Vector3 { x: 3., y: 4., z: 5. };
+    /// assert_eq!(
+    ///     Vector3::lerp_wrapped(&v1, &v2, 0.5, &min, &max),
+    ///     Vector3 { x: 1., y: 3., z: 5. }
+    /// );
+    /// ```
+    pub fn lerp_wrapped(v1: &Self, v2: &Self, factor: T, domain_min: &Self, domain_max: &Self) -> Self {
+        let wrapped = |a: T, b: T, min: T, max: T| -> T {
+            let size: T = max - min;
+            let mut d: T = b - a;
+            if d > T::HALF*size { d -= size; } else if d < -T::HALF*size { d += size; }
+            (a + d*factor - min).rem_euclid(size) + min
+        };
+        Vector3 {
+            x: wrapped(v1.x, v2.x, domain_min.x, domain_max.x),
+            y: wrapped(v1.y, v2.y, domain_min.y, domain_max.y),
+            z: wrapped(v1.z, v2.z, domain_min.z, domain_max.z)
+        }
+    }
+
+    /// Moves a [`Vector3`] interpreted as a point towards `target` by at most `max_delta`.
+    /// Snaps exactly to `target` once it is within reach; a `max_delta <= 0` leaves the point where it is.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 0., y: 0., z: 0. };
+    /// let target = Vector3 { x: 2., y: 3., z: 6. };
+    /// assert_eq!(v.move_towards(&target, 3.5), Vector3 { x: 1., y: 1.5, z: 3. });
+    /// assert_eq!(v.move_towards(&target, 10.), target);
+    /// ```
+    pub fn move_towards(&self, target: &Self, max_delta: T) -> Self {
+        if max_delta <= T::ZERO { return *self; }
+        let delta: Self = target - self;
+        let dist_sq: T = delta.magn_sq();
+        if dist_sq <= max_delta*max_delta { return *target; }
+        self + &(&delta * (max_delta / dist_sq.sqrt()))
+    }
+
     /// calculates the angle between two [`Vector3`]s.
     /// # Examples
     /// ```
     /// use cute_gorl::vector3::*;
     /// let v1 = Vector3 { x: 1., y: 1., z: 1. };
     /// let v2 = Vector3 { x: 1., y: 1., z: -1. };
-    /// assert_eq!(
-    ///     Vector3::angle_between(&v1, &v2),
-    ///     1.2309594173407747_f64
-    /// );
+    /// // the scalar product is 1 and both magnitudes are sqrt(3), so the cosine is 1/3
+    /// let expected = (1_f64 / 3.).acos();
+    /// assert!((Vector3::angle_between(&v1, &v2) - expected).abs() < 1e-12);
     /// ```
-    pub fn angle_between(v1: &Self, v2: &Self) -> f64 {
-        let a: f64 = Self::scalar(v1, v2);
-        let h: f64 = ( v1.magn_sq() * v2.magn_sq() ).sqrt();
+    pub fn angle_between(v1: &Self, v2: &Self) -> T {
+        let a: T = Self::scalar(v1, v2);
+        let h: T = ( v1.magn_sq() * v2.magn_sq() ).sqrt();
         (a / h).acos()
     }
 
@@ -251,6 +781,38 @@ impl Vector3 {
         }
     }
 
+    /// The crossproduct of two [`Vector3`]s, computed with fused multiply-adds to avoid the cancellation that
+    /// makes [`Vector3::crossp`] inaccurate for nearly parallel vectors. Slower than `crossp`, which stays the default.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v1 = Vector3 { x: 1., y: 2., z: 3. };
+    /// let v2 = Vector3 { x: 4., y: -5., z: 0. };
+    /// assert_eq!(Vector3::cross_stable(&v1, &v2), Vector3::crossp(&v1, &v2));
+    /// ```
+    pub fn cross_stable(v1: &Self, v2: &Self) -> Self {
+        Vector3 {
+            x: diff_of_products(v1.y, v2.z, v1.z, v2.y),
+            y: diff_of_products(v1.z, v2.x, v1.x, v2.z),
+            z: diff_of_products(v1.x, v2.y, v1.y, v2.x)
+        }
+    }
+
+    /// Calculates the magnitude of the crossproduct of two [`Vector3`]s, i.e. the area of the parallelogram they span
+    /// (twice the area of the triangle they span). Uses the more accurate [`Vector3::cross_stable`],
+    /// so nearly parallel vectors still get a meaningful area instead of rounding noise.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v1 = Vector3 { x: 2., y: 0., z: 0. };
+    /// let v2 = Vector3 { x: 1., y: 3., z: 0. };
+    /// assert_eq!(Vector3::cross_magnitude(&v1, &v2), 6.);
+    /// ```
+    #[inline]
+    pub fn cross_magnitude(v1: &Self, v2: &Self) -> T {
+        Self::cross_stable(v1, v2).magn()
+    }
+
     /// Rotate a [`Vector3`] by an angle around another [`Vector3`].
     /// # Examples
     /// ```
@@ -259,22 +821,19 @@ impl Vector3 {
     /// let mut v = Vector3 { x: 1., y: 1., z: 1. };
     /// let mut n = Vector3 { x: 1., y: -1., z: 1. };
     /// n.normalize();
-    /// assert_eq!(
-    ///     v.rotate(0.5*PI, &n),
-    ///     Vector3 { x: -0.8213672050459182, y: -0.3333333333333334, z: 1.4880338717125852 }
-    /// );
+    /// let expected = Vector3 { x: -0.8213672050459182, y: -0.3333333333333334, z: 1.4880338717125852 };
+    /// assert!(v.rotate(0.5*PI, &n).approx_eq(&expected, 1e-12));
     /// ```
-    pub fn rotate(&self, angle: f64, n0: &Self) -> Self {
+    pub fn rotate(&self, angle: T, n0: &Self) -> Self {
         //vrot = vcos0 + (kxv)sin0 + k(k*v)(1-cos0) 
         
-        let sin_a: f64 = angle.sin();
-        let cos_a: f64 = math::cosq(sin_a);
+        let (sin_a, cos_a) = angle.sin_cos();
         let mut temp1: Self = Self::crossp(n0, self);
-        let mut f: f64      = Self::scalar(n0, self);
+        let mut f: T      = Self::scalar(n0, self);
 
         temp1 *= sin_a;
         temp1 += &( self * cos_a );
-        f *= 1.0 - cos_a;
+        f *= T::ONE - cos_a;
         &temp1 + &(n0 * f) 
     }
     /// Rotate a [`Vector3`] by 90 degrees around another [`Vector3`].
@@ -282,7 +841,7 @@ impl Vector3 {
         //right hand rule
 
         let crossp: Self = Self::crossp(n0, self);
-        let scalar: f64  = Self::scalar(n0, self);
+        let scalar: T  = Self::scalar(n0, self);
 
         &crossp + &(n0 * scalar) 
     }
@@ -292,12 +851,13 @@ impl Vector3 {
         //right hand rule
 
         let crossp: Self = -Self::crossp(n0, self);
-        let scalar: f64  =  Self::scalar(n0, self);
+        let scalar: T  =  Self::scalar(n0, self);
         
         &crossp + &(n0 * scalar) 
     }
     
-    /// Reflect a [`Vector3`] off of a surface with a certain normal [`Vector3`].
+    /// Reflect a [`Vector3`] off of a surface with a certain normal [`Vector3`], in place.
+    /// See [`Vector3::reflected`] for a version that returns the result like [`Vector2::reflect`](crate::vector2::Vector2::reflect) does.
     /// # Examples
     /// ```
     /// use cute_gorl::vector3::*;
@@ -306,111 +866,1162 @@ impl Vector3 {
     /// v.reflect(&n);
     /// assert_eq!(v, Vector3 { x: 1., y: 1., z: -1. });
     /// ```
-    pub fn reflect(&mut self, n0: &Self) -> () {
-        let mut f: f64 = Self::scalar(self, n0);
-        f *= 2.0;
-        *self -= &(n0 * f);
+    pub fn reflect(&mut self, n0: &Self) {
+        *self = self.reflected(n0);
     }
-}
-impl Neg for Vector3 {
-    type Output = Vector3;
-    fn neg(self) -> Self::Output {
-        Self::Output {
-            x: -self.x,
-            y: -self.y,
-            z: -self.z
-        }
+
+    /// Reflect a [`Vector3`] off of a surface with a certain normal [`Vector3`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1., y: 1., z: 1. };
+    /// let n = Vector3 { x: 0., y: 0., z: 1. };
+    /// assert_eq!(v.reflected(&n), Vector3 { x: 1., y: 1., z: -1. });
+    /// assert_eq!(v, Vector3 { x: 1., y: 1., z: 1. });
+    /// ```
+    pub fn reflected(&self, n0: &Self) -> Self {
+        let f: T = T::TWO * Self::scalar(self, n0);
+        self - n0 * f
     }
-}
-impl PartialEq for Vector3{
-    fn eq(&self, other: &Vector3) -> bool{
+
+    /// Refract a [`Vector3`] through a surface with a certain normal [`Vector3`] following Snell's law.
+    /// `self` points towards the surface, `normal` away from it (against `self`), and both are assumed to be normalized.
+    /// `eta` is the ratio of the refractive index being left to the one being entered, e.g. `1. / 1.5` for air into glass.
+    /// Returns `None` on total internal reflection, in which case [`Vector3::reflected`] gives the outgoing direction.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 0., y: 0., z: -1. };
+    /// let n = Vector3 { x: 0., y: 0., z: 1. };
+    /// assert_eq!(v.refract(&n, 1. / 1.5), Some(v));
+    /// ```
+    pub fn refract(&self, normal: &Self, eta: T) -> Option<Self> {
+        let cos_i: T = -Self::scalar(self, normal);
+        let k: T = T::ONE - eta*eta * (T::ONE - cos_i*cos_i);
+        if k < T::ZERO { return None; }
+        Some(self * eta + normal * (eta*cos_i - k.sqrt()))
+    }
+
+    /// The komponent-wise minimum of two [`Vector3`]s.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v1 = Vector3 { x: 1., y: -3., z: 0. };
+    /// let v2 = Vector3 { x: -2., y: 4., z: 5. };
+    /// assert_eq!(Vector3::min(&v1, &v2), Vector3 { x: -2., y: -3., z: 0. });
+    /// ```
+    #[inline]
+    pub fn min(v1: &Self, v2: &Self) -> Self {
+        Vector3 {
+            x: v1.x.min(v2.x),
+            y: v1.y.min(v2.y),
+            z: v1.z.min(v2.z)
+        }
+    }
+
+    /// The komponent-wise maximum of two [`Vector3`]s.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v1 = Vector3 { x: 1., y: -3., z: 0. };
+    /// let v2 = Vector3 { x: -2., y: 4., z: 5. };
+    /// assert_eq!(Vector3::max(&v1, &v2), Vector3 { x: 1., y: 4., z: 5. });
+    /// ```
+    #[inline]
+    pub fn max(v1: &Self, v2: &Self) -> Self {
+        Vector3 {
+            x: v1.x.max(v2.x),
+            y: v1.y.max(v2.y),
+            z: v1.z.max(v2.z)
+        }
+    }
+
+    /// The komponent-wise absolute value of a [`Vector3`].
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: -1.5, y: 2., z: -0.25 };
+    /// assert_eq!(v.abs(), Vector3 { x: 1.5, y: 2., z: 0.25 });
+    /// ```
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Vector3 {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs()
+        }
+    }
+
+    /// Rounds each komponent of a [`Vector3`] down, towards negative infinity.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1.7, y: -1.2, z: 3. };
+    /// assert_eq!(v.floor(), Vector3 { x: 1., y: -2., z: 3. });
+    /// ```
+    #[inline]
+    pub fn floor(&self) -> Self {
+        Vector3 {
+            x: self.x.floor(),
+            y: self.y.floor(),
+            z: self.z.floor()
+        }
+    }
+
+    /// Rounds each komponent of a [`Vector3`] up, towards positive infinity.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1.2, y: -1.7, z: 3. };
+    /// assert_eq!(v.ceil(), Vector3 { x: 2., y: -1., z: 3. });
+    /// ```
+    #[inline]
+    pub fn ceil(&self) -> Self {
+        Vector3 {
+            x: self.x.ceil(),
+            y: self.y.ceil(),
+            z: self.z.ceil()
+        }
+    }
+
+    /// Rounds each komponent of a [`Vector3`] to the nearest integer. Like [`f64::round`], halfway cases round away from `0.0`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 2.5, y: -2.5, z: 0.4 };
+    /// assert_eq!(v.round(), Vector3 { x: 3., y: -3., z: 0. });
+    /// ```
+    #[inline]
+    pub fn round(&self) -> Self {
+        Vector3 {
+            x: self.x.round(),
+            y: self.y.round(),
+            z: self.z.round()
+        }
+    }
+
+    /// Calculates the reciprocal `1.0 / k` of each komponent of a [`Vector3`].
+    /// Like for [`f64`], a komponent of `0.0` yields an infinite komponent (`-0.0` yields negative infinity).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 2., y: -0.25, z: 1. };
+    /// assert_eq!(v.recip(), Vector3 { x: 0.5, y: -4., z: 1. });
+    /// assert_eq!(Vector3 { x: 1., y: 0., z: 1. }.recip().y, f64::INFINITY);
+    /// ```
+    #[inline]
+    pub fn recip(&self) -> Self {
+        Vector3 {
+            x: self.x.recip(),
+            y: self.y.recip(),
+            z: self.z.recip()
+        }
+    }
+
+    /// Returns the sign of each komponent of a [`Vector3`] as `1.0` or `-1.0`, following [`f64::signum`]
+    /// (so `0.0` yields `1.0` and `-0.0` yields `-1.0`).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 3.5, y: -0.1, z: 0. };
+    /// assert_eq!(v.signum(), Vector3 { x: 1., y: -1., z: 1. });
+    /// ```
+    #[inline]
+    pub fn signum(&self) -> Self {
+        Vector3 {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum()
+        }
+    }
+
+    /// Offsets a [`Vector3`] by a pseudo-random amount in `[-amount, amount]` along each axis.
+    /// The offset only depends on `seed`, so the same seed always jitters the same way, e.g. for reproducible scattering.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3f64 { x: 1., y: 2., z: 3. };
+    /// let j = v.jitter(42, 0.5);
+    /// assert_eq!(j, v.jitter(42, 0.5));
+    /// assert!((j.x - v.x).abs() <= 0.5 && (j.y - v.y).abs() <= 0.5);
+    /// ```
+    pub fn jitter(&self, seed: u64, amount: T) -> Self {
+        let mut state: u64 = seed;
+        let mut offset = || T::from_f64(math::signed_unit(math::splitmix64(&mut state))) * amount;
+        Self {
+            x: self.x + offset(),
+            y: self.y + offset(),
+            z: self.z + offset()
+        }
+    }
+
+    /// Returns a copy of a [`Vector3`] with the x component replaced.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1., y: 2., z: 3. };
+    /// assert_eq!(v.with_x(5.), Vector3 { x: 5., y: 2., z: 3. });
+    /// ```
+    #[inline]
+    pub fn with_x(self, x: T) -> Self {
+        Self { x, ..self }
+    }
+
+    /// Returns a copy of a [`Vector3`] with the y component replaced,
+    /// e.g. `pos.with_y(0.)` to project a position onto the ground plane.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let pos = Vector3 { x: 1., y: 2., z: 3. };
+    /// assert_eq!(pos.with_y(0.), Vector3 { x: 1., y: 0., z: 3. });
+    /// ```
+    #[inline]
+    pub fn with_y(self, y: T) -> Self {
+        Self { y, ..self }
+    }
+
+    /// Returns a copy of a [`Vector3`] with the z component replaced.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1., y: 2., z: 3. };
+    /// assert_eq!(v.with_z(-1.), Vector3 { x: 1., y: 2., z: -1. });
+    /// ```
+    #[inline]
+    pub fn with_z(self, z: T) -> Self {
+        Self { z, ..self }
+    }
+
+    /// The x and y components of a [`Vector3`] as a [`Vector2`], dropping z.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// use cute_gorl::vector2::Vector2;
+    /// let v = Vector3 { x: 1., y: 2., z: 3. };
+    /// assert_eq!(v.xy(), Vector2 { x: 1., y: 2. });
+    /// ```
+    #[inline]
+    pub fn xy(&self) -> Vector2<T> {
+        Vector2 { x: self.x, y: self.y }
+    }
+
+    /// The x and z components of a [`Vector3`] as a [`Vector2`], dropping y.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// use cute_gorl::vector2::Vector2;
+    /// let v = Vector3 { x: 1., y: 2., z: 3. };
+    /// assert_eq!(v.xz(), Vector2 { x: 1., y: 3. });
+    /// ```
+    #[inline]
+    pub fn xz(&self) -> Vector2<T> {
+        Vector2 { x: self.x, y: self.z }
+    }
+
+    /// The y and z components of a [`Vector3`] as a [`Vector2`], dropping x.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// use cute_gorl::vector2::Vector2;
+    /// let v = Vector3 { x: 1., y: 2., z: 3. };
+    /// assert_eq!(v.yz(), Vector2 { x: 2., y: 3. });
+    /// ```
+    #[inline]
+    pub fn yz(&self) -> Vector2<T> {
+        Vector2 { x: self.y, y: self.z }
+    }
+
+    /// Projects a [`Vector3`] interpreted as a point onto the plane `z = 1` by dividing by its z component,
+    /// giving `(x/z, y/z)`, the reverse of embedding a [`Vector2`] with `From<Vector2>` and scaling it.
+    /// A z of `0.0` results in infinite or NaN components, see [`Vector3::try_perspective_divide`] for a checked version.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// use cute_gorl::vector2::Vector2;
+    /// let v = Vector3 { x: 3., y: -1., z: 2. };
+    /// assert_eq!(v.perspective_divide(), Vector2 { x: 1.5, y: -0.5 });
+    /// ```
+    #[inline]
+    pub fn perspective_divide(&self) -> Vector2<T> {
+        Vector2 { x: self.x / self.z, y: self.y / self.z }
+    }
+
+    /// Checked version of [`Vector3::perspective_divide`], which is `None` if the absolute value of z
+    /// is below [`crate::EPSILON`], i.e. if the point lies (almost) in the plane of the eye.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// use cute_gorl::vector2::Vector2;
+    /// assert_eq!(Vector3 { x: 4., y: 2., z: -4. }.try_perspective_divide(), Some(Vector2 { x: -1., y: -0.5 }));
+    /// assert_eq!(Vector3 { x: 4., y: 2., z: 1e-12 }.try_perspective_divide(), None);
+    /// ```
+    pub fn try_perspective_divide(&self) -> Option<Vector2<T>> {
+        if self.z.abs() < T::EPSILON { None } else { Some(self.perspective_divide()) }
+    }
+
+    /// Determines whether or not all components of a [`Vector3`] are finite, i.e. neither infinite nor `NaN`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// assert!(Vector3 { x: 1., y: -2., z: 3. }.is_finite());
+    /// assert!( !(Vector3 { x: 0., y: 0., z: f64::NEG_INFINITY }.is_finite()) );
+    /// ```
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Determines whether or not any component of a [`Vector3`] is `NaN`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// assert!(Vector3 { x: 0., y: f64::NAN, z: 0. }.is_nan());
+    /// assert!( !(Vector3 { x: f64::INFINITY, y: 0., z: 0. }.is_nan()) );
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// Views the components of a [`Vector3`] as a slice `[x, y, z]`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1., y: 2., z: 3. };
+    /// assert_eq!(v.as_slice(), &[1., 2., 3.]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.as_ref()
+    }
+
+    /// Calculates the signed distance from a [`Vector3`] interpreted as a point to the plane through `plane_point`.
+    /// The distance is positive on the side `plane_normal` points to and negative on the other.
+    /// `plane_normal` is assumed to be normalized; otherwise the result is scaled by its magnitude.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let n = Vector3 { x: 0., y: 0., z: 1. };
+    /// let p = Vector3 { x: 5., y: -3., z: 2. };
+    /// assert_eq!(p.dist_to_plane(&Vector3 { x: 0., y: 0., z: 0.5 }, &n), 1.5);
+    /// ```
+    #[inline]
+    pub fn dist_to_plane(&self, plane_point: &Self, plane_normal: &Self) -> T {
+        Self::scalar(&(self - plane_point), plane_normal)
+    }
+
+    /// Projects a [`Vector3`] onto the line along another [`Vector3`], which does not need to be normalized.
+    /// Projecting onto the null-vector gives the null-vector.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1., y: 2., z: 3. };
+    /// let onto = Vector3 { x: 0., y: 4., z: 0. };
+    /// assert_eq!(v.project_onto(&onto), Vector3 { x: 0., y: 2., z: 0. });
+    /// ```
+    pub fn project_onto(&self, other: &Self) -> Self {
+        let len_sq: T = other.magn_sq();
+        if len_sq == T::ZERO { return Self::ZERO; }
+        other * (Self::scalar(self, other) / len_sq)
+    }
+
+    /// Projects a [`Vector3`] onto the plane through the origin with the given `normal`,
+    /// removing its component along the normal. The normal does not need to be normalized.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1., y: 1., z: 1. };
+    /// let n = Vector3 { x: 0., y: 0., z: 1. };
+    /// assert_eq!(v.project_onto_plane(&n), Vector3 { x: 1., y: 1., z: 0. });
+    /// ```
+    #[inline]
+    pub fn project_onto_plane(&self, normal: &Self) -> Self {
+        self - self.project_onto(normal)
+    }
+
+    /// Builds an orthonormal basis `(t, b, n)` around a [`Vector3`]: three mutually perpendicular unit vectors
+    /// where `n` is the normalized direction of `self`, and `crossp(t, b) == n`. Must not be used on the null-vector.
+    /// The first vector is found by crossing `n` with the axis `n` has the smallest component along,
+    /// which keeps the cross product well away from zero.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let (t, b, n) = Vector3 { x: 0., y: 0., z: 2. }.orthonormal_basis();
+    /// assert_eq!(n, Vector3 { x: 0., y: 0., z: 1. });
+    /// assert_eq!(Vector3::scalar(&t, &n), 0.);
+    /// assert_eq!(Vector3::crossp(&t, &b), n);
+    /// ```
+    pub fn orthonormal_basis(&self) -> (Self, Self, Self) {
+        let (n, _) = self.normalized_and_length();
+        let (ax, ay, az) = (n.x.abs(), n.y.abs(), n.z.abs());
+        let axis: Self = if ax <= ay && ax <= az { Vector3 { x: T::ONE, y: T::ZERO, z: T::ZERO } }
+                         else if ay <= az { Vector3 { x: T::ZERO, y: T::ONE, z: T::ZERO } }
+                         else { Vector3 { x: T::ZERO, y: T::ZERO, z: T::ONE } };
+        let mut t: Self = Self::crossp(&n, &axis);
+        t.normalize();
+        let b: Self = Self::crossp(&n, &t);
+        (t, b, n)
+    }
+
+    /// Compares two [`Vector3`]s lexicographically like [`PartialOrd`], but as a total order
+    /// following [`f64::total_cmp`], so `NaN`s are sorted consistently (and `-0.0` comes before `0.0`).
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let mut v = vec![Vector3 { x: 1., y: f64::NAN, z: 0. }, Vector3 { x: 1., y: 0., z: 0. }];
+    /// v.sort_by(|a, b| a.total_cmp(b));
+    /// assert_eq!(v[0], Vector3 { x: 1., y: 0., z: 0. });
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.x.total_cmp(&other.x)
+            .then_with(|| self.y.total_cmp(&other.y))
+            .then_with(|| self.z.total_cmp(&other.z))
+    }
+
+    /// Scales a [`Vector3`] interpreted as a point away from (or towards) a `pivot` point,
+    /// with each axis scaled by the matching komponent of `factors`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let p = Vector3 { x: 3., y: 2., z: -1. };
+    /// let pivot = Vector3 { x: 1., y: 1., z: 1. };
+    /// let factors = Vector3 { x: 2., y: 2., z: 2. };
+    /// assert_eq!(p.scale_around(&pivot, &factors), Vector3 { x: 5., y: 3., z: -3. });
+    /// ```
+    pub fn scale_around(&self, pivot: &Self, factors: &Self) -> Self {
+        Vector3 {
+            x: pivot.x + (self.x - pivot.x) * factors.x,
+            y: pivot.y + (self.y - pivot.y) * factors.y,
+            z: pivot.z + (self.z - pivot.z) * factors.z
+        }
+    }
+
+    /// Clamps each komponent of a [`Vector3`] to the range between the matching komponents of `min` and `max`,
+    /// e.g. to keep a point inside of a box. Unlike [`f64::clamp`] this never panics:
+    /// if `min` is greater than `max` on an axis, that komponent is set to the one of `max`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let min = Vector3 { x: 0., y: 0., z: 0. };
+    /// let max = Vector3 { x: 10., y: 5., z: 1. };
+    /// assert_eq!(Vector3 { x: 3., y: 4., z: 0.5 }.clamp_components(&min, &max), Vector3 { x: 3., y: 4., z: 0.5 });
+    /// assert_eq!(Vector3 { x: 3., y: 4., z: 2. }.clamp_components(&min, &max), Vector3 { x: 3., y: 4., z: 1. });
+    /// assert_eq!(Vector3 { x: 11., y: -4., z: 2. }.clamp_components(&min, &max), Vector3 { x: 10., y: 0., z: 1. });
+    /// ```
+    pub fn clamp_components(&self, min: &Self, max: &Self) -> Self {
+        Vector3 {
+            x: self.x.max(min.x).min(max.x),
+            y: self.y.max(min.y).min(max.y),
+            z: self.z.max(min.z).min(max.z)
+        }
+    }
+
+    /// Interpolates along a (uniform) Catmull-Rom spline, which passes through all of its control points.
+    /// Returns the point at `t` on the segment from `p1` (`t = 0`) to `p2` (`t = 1`); `p0` and `p3` are the neighbouring
+    /// control points that shape it. The tangent at each control point is half the difference of its neighbours,
+    /// i.e. `(p2 - p0) / 2` at `p1` and `(p3 - p1) / 2` at `p2`, so consecutive segments join smoothly.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let p0 = Vector3 { x: 0., y: 0., z: 0. };
+    /// let p1 = Vector3 { x: 1., y: 1., z: 2. };
+    /// let p2 = Vector3 { x: 2., y: 1., z: 2. };
+    /// let p3 = Vector3 { x: 3., y: 0., z: 0. };
+    /// assert_eq!(Vector3::catmull_rom(&p0, &p1, &p2, &p3, 0.5), Vector3 { x: 1.5, y: 1.125, z: 2.25 });
+    /// ```
+    pub fn catmull_rom(p0: &Self, p1: &Self, p2: &Self, p3: &Self, t: T) -> Self {
+        let (t2, t3) = (t*t, t*t*t);
+        // cubic hermite basis, exact at both ends
+        let h00: T = T::TWO*t3 - T::from_f64(3.)*t2 + T::ONE;
+        let h10: T = t3 - T::TWO*t2 + t;
+        let h01: T = -T::TWO*t3 + T::from_f64(3.)*t2;
+        let h11: T = t3 - t2;
+        let m1: Self = (p2 - p0) * T::HALF;
+        let m2: Self = (p3 - p1) * T::HALF;
+        p1 * h00 + m1 * h10 + p2 * h01 + m2 * h11
+    }
+
+    /// Evaluates the quadratic Bézier curve from `p0` to `p2` with the control point `p1` at `t`, using de Casteljau's algorithm.
+    /// The curve starts exactly at `p0` for `t = 0` and ends exactly at `p2` for `t = 1`, but usually does not pass through `p1`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let p0 = Vector3 { x: 0., y: 0., z: 1. };
+    /// let p1 = Vector3 { x: 1., y: 2., z: 1. };
+    /// let p2 = Vector3 { x: 2., y: 0., z: 1. };
+    /// assert_eq!(Vector3::bezier_quadratic(&p0, &p1, &p2, 0.5), Vector3 { x: 1., y: 1., z: 1. });
+    /// assert_eq!(Vector3::bezier_quadratic(&p0, &p1, &p2, 1.), p2);
+    /// ```
+    pub fn bezier_quadratic(p0: &Self, p1: &Self, p2: &Self, t: T) -> Self {
+        let a: Self = Self::lerp(p0, p1, t);
+        let b: Self = Self::lerp(p1, p2, t);
+        Self::lerp(&a, &b, t)
+    }
+
+    /// Evaluates the cubic Bézier curve from `p0` to `p3` with the control points `p1` and `p2` at `t`, using de Casteljau's algorithm.
+    /// The curve starts exactly at `p0` for `t = 0` and ends exactly at `p3` for `t = 1`,
+    /// leaving `p0` towards `p1` and arriving at `p3` from the direction of `p2`.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let p0 = Vector3 { x: 0., y: 0., z: 1. };
+    /// let p1 = Vector3 { x: 0., y: 4., z: 1. };
+    /// let p2 = Vector3 { x: 4., y: 4., z: 1. };
+    /// let p3 = Vector3 { x: 4., y: 0., z: 1. };
+    /// assert_eq!(Vector3::bezier_cubic(&p0, &p1, &p2, &p3, 0.5), Vector3 { x: 2., y: 3., z: 1. });
+    /// assert_eq!(Vector3::bezier_cubic(&p0, &p1, &p2, &p3, 0.), p0);
+    /// ```
+    pub fn bezier_cubic(p0: &Self, p1: &Self, p2: &Self, p3: &Self, t: T) -> Self {
+        let a: Self = Self::bezier_quadratic(p0, p1, p2, t);
+        let b: Self = Self::bezier_quadratic(p1, p2, p3, t);
+        Self::lerp(&a, &b, t)
+    }
+
+    /// Moves a [`Vector3`] towards a `target` like a critically damped spring, e.g. for a camera following a player.
+    /// `velocity` is the current speed of the movement and gets updated in place, so the same variable must be passed in every frame.
+    /// `smooth_time` is roughly the time it takes to reach the target and is clamped to at least `0.0001`, `dt` is the time step.
+    /// The result never overshoots the target.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let target = Vector3 { x: 10., y: 0., z: -5. };
+    /// let mut pos = NULL;
+    /// let mut velocity = NULL;
+    /// for _ in 0..120 {
+    ///     pos = Vector3::smooth_damp(&pos, &target, &mut velocity, 0.3, 1. / 60.);
+    /// }
+    /// assert!(Vector3::dist(&pos, &target) < 0.01);
+    /// ```
+    pub fn smooth_damp(current: &Self, target: &Self, velocity: &mut Self, smooth_time: T, dt: T) -> Self {
+        let omega: T = T::TWO / smooth_time.max(T::from_f64(0.0001));
+        let x: T = omega * dt;
+        // approximation of exp(-x) that is accurate enough for small steps
+        let decay: T = T::ONE / (T::ONE + x + T::from_f64(0.48)*x*x + T::from_f64(0.235)*x*x*x);
+        let change: Self = current - target;
+        let temp: Self = (*velocity + change * omega) * dt;
+        *velocity = (*velocity - temp * omega) * decay;
+        let res: Self = target + (change + temp) * decay;
+        // stop at the target if the step would have moved past it
+        if Self::scalar(&(target - current), &(res - target)) > T::ZERO {
+            *velocity = Self::ZERO;
+            return *target;
+        }
+        res
+    }
+
+    /// Converts a [`Vector3`] to an `[f32; 3]`, e.g. for uploading it to a GPU buffer.
+    /// Each komponent is rounded to the nearest `f32`, which keeps only about 7 significant digits,
+    /// and values beyond the range of `f32` become infinite.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1.5, y: 0.1, z: -2. };
+    /// assert_eq!(v.to_f32_array(), [1.5_f32, 0.1_f32, -2_f32]);
+    /// ```
+    #[inline]
+    pub fn to_f32_array(&self) -> [f32; 3] {
+        [self.x.to_f64() as f32, self.y.to_f64() as f32, self.z.to_f64() as f32]
+    }
+
+    /// Converts a [`Vector3`] to one with another komponent type, e.g. to a [`Vector3f32`] for a vertex buffer.
+    /// Going from `f64` to `f32` rounds each komponent to the nearest `f32`, the other way round is exact.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let v = Vector3 { x: 1.5, y: 0.1, z: -2. };
+    /// let small: Vector3f32 = v.cast();
+    /// assert_eq!(small, Vector3 { x: 1.5_f32, y: 0.1_f32, z: -2_f32 });
+    /// assert_eq!(small.cast::<f64>().x, 1.5);
+    /// ```
+    #[inline]
+    pub fn cast<U: Scalar>(&self) -> Vector3<U> {
+        Vector3 {
+            x: U::from_f64(self.x.to_f64()),
+            y: U::from_f64(self.y.to_f64()),
+            z: U::from_f64(self.z.to_f64())
+        }
+    }
+
+    /// Calculates the smallest axis-aligned box containing a number of [`Vector3`]s interpreted as points,
+    /// as its `(min, max)` corners, or `None` if there are no points.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// let points = [Vector3 { x: 1., y: -2., z: 0. }, Vector3 { x: -3., y: 0., z: 4. }, Vector3 { x: 2., y: 5., z: -1. }];
+    /// assert_eq!(Vector3::bounding_box(&points), Some((Vector3 { x: -3., y: -2., z: -1. }, Vector3 { x: 2., y: 5., z: 4. })));
+    /// assert_eq!(Vector3f64::bounding_box(&[]), None);
+    /// ```
+    pub fn bounding_box(points: &[Self]) -> Option<(Self, Self)> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold((*first, *first), |(min, max), p| (Self::min(&min, p), Self::max(&max, p))))
+    }
+
+    /// The index of the largest komponent of a [`Vector3`]: `0` for x, `1` for y and `2` for z. On a tie the lowest index wins.
+    /// Komponents are compared by value, so use [`Vector3::abs`] first to find the axis a direction is closest to,
+    /// e.g. the longest side of a bounding box to split along.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// assert_eq!(Vector3 { x: 1., y: 0.5, z: 9. }.max_axis(), 2);
+    /// assert_eq!(Vector3 { x: 1., y: 3., z: 3. }.max_axis(), 1);
+    /// ```
+    pub fn max_axis(&self) -> usize {
+        let mut axis: usize = 0;
+        if self.y > self.x { axis = 1; }
+        if self.z > self.as_slice()[axis] { axis = 2; }
+        axis
+    }
+
+    /// The index of the smallest komponent of a [`Vector3`]: `0` for x, `1` for y and `2` for z. On a tie the lowest index wins.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// assert_eq!(Vector3 { x: 1., y: -0.5, z: 9. }.min_axis(), 1);
+    /// assert_eq!(Vector3 { x: 0., y: 3., z: 0. }.min_axis(), 0);
+    /// ```
+    pub fn min_axis(&self) -> usize {
+        let mut axis: usize = 0;
+        if self.y < self.x { axis = 1; }
+        if self.z < self.as_slice()[axis] { axis = 2; }
+        axis
+    }
+
+    /// A random normalized [`Vector3`], uniformly distributed over the unit sphere. Only available with the `rand` feature.
+    /// The height `z` is drawn uniformly from `[-1, 1]` and the angle around the z-axis from `[0, 2PI)`,
+    /// which by Archimedes' hat-box theorem covers the sphere evenly.
+    /// # Examples
+    /// ```
+    /// use cute_gorl::vector3::*;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// assert!(Vector3::random_unit(&mut rng).is_normalized_eps(1e-12));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_unit(rng: &mut impl rand::Rng) -> Self {
+        let z: f64 = rng.gen_range(-1.0..=1.0);
+        let (s, c) = rng.gen_range(0.0..std::f64::consts::TAU).sin_cos();
+        let r: f64 = (1. - z*z).sqrt();
+        Vector3 { x: T::from_f64(r*c), y: T::from_f64(r*s), z: T::from_f64(z) }
+    }
+}
+impl<T: Scalar> Neg for Vector3<T> {
+    type Output = Vector3<T>;
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z
+        }
+    }
+}
+/// Negates a borrowed [`Vector3`] into a new one.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let v = Vector3 { x: 1., y: -2., z: 0. };
+/// let w = Vector3 { x: 0.5, y: 0.5, z: 0.5 };
+/// assert_eq!(-&v + w, Vector3 { x: -0.5, y: 2.5, z: 0.5 });
+/// ```
+impl<T: Scalar> Neg for &Vector3<T> {
+    type Output = Vector3<T>;
+    fn neg(self) -> Self::Output {
+        Vector3 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z
+        }
+    }
+}
+impl<T: Scalar> fmt::Debug for Vector3<T> {
+    /// Prints the compact `Vec3(x, y, z)`, or the usual struct form with `{:#?}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Vector3")
+                .field("x", &self.x)
+                .field("y", &self.y)
+                .field("z", &self.z)
+                .finish()
+        } else {
+            // the komponents go through the same formatter, so flags like `{:.2?}` apply to each of them
+            f.write_str("Vec3(")?;
+            fmt::Display::fmt(&self.x, f)?;
+            f.write_str(", ")?;
+            fmt::Display::fmt(&self.y, f)?;
+            f.write_str(", ")?;
+            fmt::Display::fmt(&self.z, f)?;
+            f.write_str(")")
+        }
+    }
+}
+impl<T: Scalar> PartialEq for Vector3<T>{
+    fn eq(&self, other: &Vector3<T>) -> bool{
         (self.x == other.x) &&
         (self.y == other.y) &&
         (self.z == other.z)
     }
-    fn ne(&self, other: &Vector3) -> bool{
-        (self.x != other.x) &&
-        (self.y != other.y) &&
-        (self.z != other.z)
+}
+/// Orders [`Vector3`]s lexicographically, by x first, then by y and then by z.
+/// This ordering has no geometric meaning, it is only there to sort vectors into a canonical order, e.g. for deduplication.
+/// Vectors with a `NaN` komponent are unordered; see [`Vector3::total_cmp`] for an ordering that includes them.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// assert!(Vector3 { x: 1., y: 5., z: 0. } < Vector3 { x: 2., y: 0., z: 0. });
+/// assert!(Vector3 { x: 1., y: 2., z: 3. } > Vector3 { x: 1., y: 2., z: -3. });
+/// ```
+impl<T: Scalar> PartialOrd for Vector3<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.x.partial_cmp(&other.x)? {
+            Ordering::Equal => match self.y.partial_cmp(&other.y)? {
+                Ordering::Equal => self.z.partial_cmp(&other.z),
+                ord => Some(ord)
+            },
+            ord => Some(ord)
+        }
     }
 }
-impl Add for &Vector3{
-    type Output = Vector3;
-    fn add(self, other: &Vector3) -> Self::Output {
+impl<T: Scalar> Add for &Vector3<T>{
+    type Output = Vector3<T>;
+    fn add(self, other: &Vector3<T>) -> Self::Output {
         Vector3 { x: self.x + other.x,
                   y: self.y + other.y,
                   z: self.z + other.z }
     }
 }
-impl Sub for &Vector3{
-    type Output = Vector3;
-    fn sub(self, other: &Vector3) -> Self::Output {
+impl<T: Scalar> Sub for &Vector3<T>{
+    type Output = Vector3<T>;
+    fn sub(self, other: &Vector3<T>) -> Self::Output {
         Vector3 { x: self.x-other.x,
                   y: self.y-other.y,
                   z: self.z-other.z }
     }
 }
-impl Mul<f64> for &Vector3{
-    type Output = Vector3;
-    fn mul(self, factor: f64) -> Self::Output {
+impl<T: Scalar> Mul<T> for &Vector3<T>{
+    type Output = Vector3<T>;
+    fn mul(self, factor: T) -> Self::Output {
         Vector3 { x: self.x*factor, 
                   y: self.y*factor, 
                   z: self.z*factor }
     }
 }
-impl Mul<&Vector3> for f64{
-    type Output = Vector3;
-    fn mul(self, vector: &Vector3) -> Self::Output {
-        Vector3 { x: vector.x*self,
-                  y: vector.y*self,
-                  z: vector.z*self }
+// `impl<T: Scalar> Mul<Vector3<T>> for T` is ruled out by the orphan rule, so these are spelled out per scalar
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {$(
+        impl Mul<&Vector3<$t>> for $t{
+            type Output = Vector3<$t>;
+            fn mul(self, vector: &Vector3<$t>) -> Self::Output {
+                Vector3 { x: vector.x*self,
+                          y: vector.y*self,
+                          z: vector.z*self }
+            }
+        }
+        impl Mul<Vector3<$t>> for $t{
+            type Output = Vector3<$t>;
+            fn mul(self, vector: Vector3<$t>) -> Self::Output {
+                Vector3 { x: vector.x*self,
+                          y: vector.y*self,
+                          z: vector.z*self }
+            }
+        }
+    )*};
+}
+impl_scalar_mul!(f32, f64);
+impl<T: Scalar> Div<T> for &Vector3<T>{
+    type Output = Vector3<T>;
+    fn div(self, quotient: T) -> Self::Output {
+        let f: T = T::ONE/quotient;
+        Vector3 { x: self.x*f,
+                  y: self.y*f,
+                  z: self.z*f }
+    }
+}
+impl<T: Scalar> Add for Vector3<T>{
+    type Output = Vector3<T>;
+    fn add(self, other: Vector3<T>) -> Self::Output {
+        Vector3 { x: self.x + other.x,
+                  y: self.y + other.y,
+                  z: self.z + other.z }
+    }
+}
+impl<T: Scalar> Add<&Vector3<T>> for Vector3<T>{
+    type Output = Vector3<T>;
+    fn add(self, other: &Vector3<T>) -> Self::Output {
+        Vector3 { x: self.x + other.x,
+                  y: self.y + other.y,
+                  z: self.z + other.z }
+    }
+}
+impl<T: Scalar> Add<Vector3<T>> for &Vector3<T>{
+    type Output = Vector3<T>;
+    fn add(self, other: Vector3<T>) -> Self::Output {
+        Vector3 { x: self.x + other.x,
+                  y: self.y + other.y,
+                  z: self.z + other.z }
+    }
+}
+impl<T: Scalar> Sub for Vector3<T>{
+    type Output = Vector3<T>;
+    fn sub(self, other: Vector3<T>) -> Self::Output {
+        Vector3 { x: self.x - other.x,
+                  y: self.y - other.y,
+                  z: self.z - other.z }
+    }
+}
+impl<T: Scalar> Sub<&Vector3<T>> for Vector3<T>{
+    type Output = Vector3<T>;
+    fn sub(self, other: &Vector3<T>) -> Self::Output {
+        Vector3 { x: self.x - other.x,
+                  y: self.y - other.y,
+                  z: self.z - other.z }
+    }
+}
+impl<T: Scalar> Sub<Vector3<T>> for &Vector3<T>{
+    type Output = Vector3<T>;
+    fn sub(self, other: Vector3<T>) -> Self::Output {
+        Vector3 { x: self.x - other.x,
+                  y: self.y - other.y,
+                  z: self.z - other.z }
+    }
+}
+impl<T: Scalar> Mul<T> for Vector3<T>{
+    type Output = Vector3<T>;
+    fn mul(self, factor: T) -> Self::Output {
+        Vector3 { x: self.x*factor,
+                  y: self.y*factor,
+                  z: self.z*factor }
     }
 }
-impl Div<f64> for &Vector3{
-    type Output = Vector3;
-    fn div(self, quotient: f64) -> Self::Output {
-        let f: f64 = 1./quotient;
+impl<T: Scalar> Div<T> for Vector3<T>{
+    type Output = Vector3<T>;
+    fn div(self, quotient: T) -> Self::Output {
+        let f: T = T::ONE/quotient;
         Vector3 { x: self.x*f,
                   y: self.y*f,
                   z: self.z*f }
     }
 }
-impl AddAssign<&Self> for Vector3 {
+/// Adds another [`Vector3`] in place, taken either by value or by reference.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let mut v = Vector3 { x: 1., y: 2., z: 3. };
+/// v += Vector3 { x: 0.5, y: 0.5, z: -1. };
+/// assert_eq!(v, Vector3 { x: 1.5, y: 2.5, z: 2. });
+/// ```
+impl<T: Scalar> AddAssign<Self> for Vector3<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+impl<T: Scalar> AddAssign<&Self> for Vector3<T> {
     fn add_assign(&mut self, other: &Self) {
         self.x += other.x;
         self.y += other.y;
         self.z += other.z;
     }
 }
-impl SubAssign<&Self> for Vector3 {
+/// Subtracts another [`Vector3`] in place, taken either by value or by reference.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let mut v = Vector3 { x: 1., y: 2., z: 3. };
+/// v -= Vector3 { x: 0.5, y: 0.5, z: -1. };
+/// assert_eq!(v, Vector3 { x: 0.5, y: 1.5, z: 4. });
+/// ```
+impl<T: Scalar> SubAssign<Self> for Vector3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+impl<T: Scalar> SubAssign<&Self> for Vector3<T> {
     fn sub_assign(&mut self, other: &Self) {
         self.x -= other.x;
         self.y -= other.y;
         self.z -= other.z;
     }
 }
-impl MulAssign<f64> for Vector3 {
-    fn mul_assign(&mut self, other: f64) {
+impl<T: Scalar> MulAssign<T> for Vector3<T> {
+    fn mul_assign(&mut self, other: T) {
         self.x *= other;
         self.y *= other;
         self.z *= other;
     }
 }
-impl DivAssign<f64> for Vector3 {
-    fn div_assign(&mut self, other: f64) {
-        let f: f64 = 1./other;
+impl<T: Scalar> DivAssign<T> for Vector3<T> {
+    fn div_assign(&mut self, other: T) {
+        let f: T = T::ONE/other;
         self.x *= f;
         self.y *= f;
         self.z *= f;
     }
 }
-impl From<Vector2> for Vector3 {
+impl<T: Scalar> Sum for Vector3<T> {
+    fn sum<I: Iterator<Item = Vector3<T>>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, v| acc + v)
+    }
+}
+impl<'a, T: Scalar> Sum<&'a Vector3<T>> for Vector3<T> {
+    fn sum<I: Iterator<Item = &'a Vector3<T>>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, v| acc + v)
+    }
+}
+
+impl<T: Scalar> IntoIterator for Vector3<T> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, 3>;
+    /// Iterates over the components of a [`Vector3`] in order.
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y, self.z].into_iter()
+    }
+}
+impl<T: Scalar> From<Vector2<T>> for Vector3<T> {
     #[inline]
-    fn from(v2: Vector2) -> Vector3 {
+    fn from(v2: Vector2<T>) -> Vector3<T> {
         Vector3 {
             x: v2.x,
             y: v2.y,
-            z: 0.0
+            z: T::ZERO
         }
     }
-}
\ No newline at end of file
+}
+/// Reads a [`Vector3`] from a slice of exactly 3 komponents, failing for any other length.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let flat: Vec<f64> = vec![0., 1., 2., 3., 4.];
+/// assert_eq!(Vector3::try_from(&flat[1..4]).ok(), Some(Vector3 { x: 1., y: 2., z: 3. }));
+/// assert!(Vector3::try_from(&flat[..]).is_err());
+/// ```
+impl<T: Scalar> TryFrom<&[T]> for Vector3<T> {
+    type Error = TryFromSliceError;
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        let [x, y, z] = <[T; 3]>::try_from(slice)?;
+        Ok(Vector3 { x, y, z })
+    }
+}
+impl<T: Scalar> AsRef<[T; 3]> for Vector3<T> {
+    fn as_ref(&self) -> &[T; 3] {
+        // SAFETY: Vector3 is #[repr(C)] with three f64 fields, so it has the layout of [f64; 3].
+        unsafe { &*(self as *const Self as *const [T; 3]) }
+    }
+}
+/// Reflects a direction [`Vector3`] off of several surfaces in order, returning the final direction.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let v = Vector3 { x: 1., y: 2., z: 3. };
+/// let normals = [
+///     Vector3 { x: 1., y: 0., z: 0. },
+///     Vector3 { x: 0., y: 1., z: 0. },
+///     Vector3 { x: 0., y: 0., z: 1. }
+/// ];
+/// assert_eq!(reflect_sequence(&v, &normals), -v);
+/// ```
+pub fn reflect_sequence(dir: &Vector3, normals: &[Vector3]) -> Vector3 {
+    normals.iter().fold(*dir, |res, n0| res.reflected(n0))
+}
+
+/// Calculates the volume enclosed by a closed triangle mesh by summing the signed volumes of the
+/// tetrahedra spanned by the origin and each face. Each face indexes into `points` and has to be wound
+/// counter-clockwise when seen from outside of the mesh; if every face is wound the other way round the result is negative.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let points = [
+///     Vector3 { x: 0., y: 0., z: 0. },
+///     Vector3 { x: 1., y: 0., z: 0. },
+///     Vector3 { x: 0., y: 1., z: 0. },
+///     Vector3 { x: 0., y: 0., z: 1. }
+/// ];
+/// let faces = [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]];
+/// assert_eq!(convex_volume(&points, &faces), 1. / 6.);
+/// ```
+pub fn convex_volume(points: &[Vector3], faces: &[[usize; 3]]) -> f64 {
+    let mut volume: f64 = 0.0;
+    for [a, b, c] in faces {
+        let cross: Vector3 = Vector3::crossp(&points[*b], &points[*c]);
+        volume += Vector3::scalar(&points[*a], &cross);
+    }
+    volume / 6.0
+}
+
+/// Calculates the centroid of the volume enclosed by a closed triangle mesh, wound the same way as for [`convex_volume`].
+/// Returns `None` if the mesh encloses no volume.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let points = [
+///     Vector3 { x: 0., y: 0., z: 0. },
+///     Vector3 { x: 4., y: 0., z: 0. },
+///     Vector3 { x: 0., y: 4., z: 0. },
+///     Vector3 { x: 0., y: 0., z: 4. }
+/// ];
+/// let faces = [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]];
+/// assert_eq!(convex_centroid(&points, &faces), Some(Vector3 { x: 1., y: 1., z: 1. }));
+/// ```
+pub fn convex_centroid(points: &[Vector3], faces: &[[usize; 3]]) -> Option<Vector3> {
+    let mut volume: f64 = 0.0;
+    let mut weighted: Vector3 = NULL;
+    for [a, b, c] in faces {
+        let (a, b, c) = (&points[*a], &points[*b], &points[*c]);
+        // six times the signed volume of the tetrahedron (origin, a, b, c), whose centroid is (a+b+c)/4
+        let v: f64 = Vector3::scalar(a, &Vector3::crossp(b, c));
+        volume += v;
+        weighted += &(&(&(a + b) + c) * v);
+    }
+    if volume == 0.0 { return None; }
+    Some(&weighted / (4.0 * volume))
+}
+
+/// Calculates Schlick's approximation of the fraction of light reflected off of a surface, in `[0, 1]`.
+/// `incident` points towards the surface, `normal` away from it, and both are assumed to be normalized.
+/// `eta` is the same ratio as in [`Vector3::refract`], the refractive index being left over the one being entered,
+/// e.g. `1. / 1.5` for air into glass. On total internal reflection, i.e. whenever [`Vector3::refract`] returns `None`,
+/// the result is `1.0`. Otherwise the rest of the light is refracted, so the result can be used to weight
+/// [`Vector3::reflected`] against [`Vector3::refract`].
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let incident = Vector3 { x: 0., y: 0., z: -1. };
+/// let normal = Vector3 { x: 0., y: 0., z: 1. };
+/// assert!((fresnel(&incident, &normal, 1. / 1.5) - 0.04).abs() < 1e-12);
+/// let steep = Vector3 { x: 0.8, y: 0., z: -0.6 };
+/// assert_eq!(steep.refract(&normal, 1.5), None);
+/// assert_eq!(fresnel(&steep, &normal, 1.5), 1.);
+/// ```
+pub fn fresnel(incident: &Vector3, normal: &Vector3, eta: f64) -> f64 {
+    let mut r0: f64 = (1.0 - eta) / (1.0 + eta);
+    r0 *= r0;
+    let mut cos: f64 = Vector3::scalar(incident, normal).abs().min(1.0);
+    if eta > 1.0 {
+        // leaving the denser medium, Schlick's formula needs the angle on the side of the thinner one
+        let sin2_t: f64 = eta*eta * (1.0 - cos*cos);
+        if sin2_t > 1.0 { return 1.0; }
+        cos = (1.0 - sin2_t).sqrt();
+    }
+    let f: f64 = 1.0 - cos;
+    (r0 + (1.0 - r0) * f*f*f*f*f).clamp(0.0, 1.0)
+}
+
+/// Fits a plane to a set of [`Vector3`]s interpreted as points, minimizing the sum of squared distances.
+/// Returns a point on the plane (the centroid of the points) and the plane's normalized normal, which is the
+/// direction in which the points vary the least. Which of the two opposite normals is returned is unspecified.
+/// Returns `None` for fewer than three points; for collinear points the normal is any direction perpendicular to their line.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let points = [
+///     Vector3 { x: 0., y: 0., z: 2. },
+///     Vector3 { x: 1., y: 0., z: 2. },
+///     Vector3 { x: 0., y: 1., z: 2. },
+///     Vector3 { x: 1., y: 1., z: 2. }
+/// ];
+/// let (center, normal) = fit_plane(&points).unwrap();
+/// assert!(center.approx_eq_default(&Vector3 { x: 0.5, y: 0.5, z: 2. }));
+/// assert!((normal.z.abs() - 1.).abs() < 1e-12);
+/// ```
+pub fn fit_plane(points: &[Vector3]) -> Option<(Vector3, Vector3)> {
+    if points.len() < 3 { return None; }
+    let mut center: Vector3 = NULL;
+    for p in points { center += p; }
+    center /= points.len() as f64;
+
+    let mut cov = [[0.0_f64; 3]; 3];
+    for p in points {
+        let d: Vector3 = p - &center;
+        let d = [d.x, d.y, d.z];
+        for i in 0..3 {
+            for j in 0..3 { cov[i][j] += d[i]*d[j]; }
+        }
+    }
+    let (values, vectors) = symmetric_eigen(cov);
+    let mut smallest: usize = 0;
+    for i in 1..3 {
+        if values[i] < values[smallest] { smallest = i; }
+    }
+    Some((center, vectors[smallest]))
+}
+
+/// Eigenvalues and normalized eigenvectors of a symmetric 3x3 matrix, using cyclic Jacobi rotations.
+fn symmetric_eigen(mut a: [[f64; 3]; 3]) -> ([f64; 3], [Vector3; 3]) {
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for _ in 0..32 {
+        let off: f64 = a[0][1]*a[0][1] + a[0][2]*a[0][2] + a[1][2]*a[1][2];
+        let diag: f64 = a[0][0]*a[0][0] + a[1][1]*a[1][1] + a[2][2]*a[2][2];
+        if off <= 1e-30 * diag || off == 0.0 { break; }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == 0.0 { continue; }
+            // rotation that zeroes a[p][q]
+            let theta: f64 = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t: f64 = theta.signum() / (theta.abs() + (theta*theta + 1.0).sqrt());
+            let c: f64 = 1.0 / (t*t + 1.0).sqrt();
+            let s: f64 = t * c;
+            for row in a.iter_mut() {
+                let (rp, rq) = (row[p], row[q]);
+                row[p] = c*rp - s*rq;
+                row[q] = s*rp + c*rq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            for (k, (pk, qk)) in row_p.iter().zip(row_q.iter()).enumerate() {
+                a[p][k] = c*pk - s*qk;
+                a[q][k] = s*pk + c*qk;
+            }
+            for row in v.iter_mut() {
+                let (rp, rq) = (row[p], row[q]);
+                row[p] = c*rp - s*rq;
+                row[q] = s*rp + c*rq;
+            }
+        }
+    }
+    let column = |i: usize| Vector3 { x: v[0][i], y: v[1][i], z: v[2][i] };
+    ([a[0][0], a[1][1], a[2][2]], [column(0), column(1), column(2)])
+}
+
+/// `a*b - c*d` with Kahan's algorithm, exact up to a few rounding errors of the result itself.
+#[inline]
+fn diff_of_products<T: Scalar>(a: T, b: T, c: T, d: T) -> T {
+    let cd: T = c * d;
+    let err: T = (-c).mul_add(d, cd);
+    a.mul_add(b, -cd) + err
+}
+
+/// Writes [`Vector3`]s as Wavefront OBJ vertex lines (`v x y z`), one per point.
+/// The numbers are written precisely enough for [`from_obj_vertices`] to read back the exact same points.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let points = [Vector3 { x: 1., y: 2.5, z: -3. }, Vector3 { x: 0., y: 0., z: 0.125 }];
+/// assert_eq!(to_obj_vertices(&points), "v 1 2.5 -3\nv 0 0 0.125\n");
+/// ```
+pub fn to_obj_vertices(points: &[Vector3]) -> String {
+    let mut res = String::new();
+    for p in points {
+        res += &format!("v {} {} {}\n", p.x, p.y, p.z);
+    }
+    res
+}
+
+/// Reads the vertices out of Wavefront OBJ text. Every line other than a well-formed `v x y z` line,
+/// e.g. faces, normals (`vn`) and comments, is ignored; a fourth `w` component is ignored as well.
+/// # Examples
+/// ```
+/// use cute_gorl::vector3::*;
+/// let text = "# a triangle\nv 0 0 0\nv 1 0 0\nvn 0 0 1\nv 0 1 0\nf 1 2 3\n";
+/// assert_eq!(from_obj_vertices(text).len(), 3);
+/// ```
+pub fn from_obj_vertices(text: &str) -> Vec<Vector3> {
+    let mut res = Vec::new();
+    for line in text.lines() {
+        let mut words = line.split_whitespace();
+        if words.next() != Some("v") { continue; }
+        let nums: Vec<f64> = words.take(3).filter_map(|w| w.parse().ok()).collect();
+        if let [x, y, z] = nums[..] {
+            res.push(Vector3 { x, y, z });
+        }
+    }
+    res
+}